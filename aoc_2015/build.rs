@@ -0,0 +1,13 @@
+// Generates one test per example file and part, see tests/examples.rs
+use std::env;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    println!("cargo:rerun-if-changed=examples");
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let source = aoc_examples::generate_tests(Path::new(&crate_dir), "aoc_2015::Aoc2015")
+        .unwrap_or_else(|why| panic!("{}", why));
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("examples.rs"), source).unwrap();
+}
@@ -0,0 +1,71 @@
+use aoc_common::{Answer, AocError, Solver};
+
+fn calculate_delta(x: char) -> i32 {
+    match x {
+        '(' => 1,
+        ')' => -1,
+        _ => 0,
+    }
+}
+
+pub fn count_parenthesis(address: &str) -> i32 {
+    address.chars().map(calculate_delta).sum()
+}
+
+// None when santa never gets to the basement
+pub fn find_basement_position(address: &str) -> Option<u32> {
+    let mut current_address = 0;
+    for (position, value) in address.chars().enumerate() {
+        current_address += calculate_delta(value);
+        if current_address == -1 {
+            return Some((position + 1) as u32);
+        }
+    }
+    None
+}
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        Ok(count_parenthesis(input).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        match find_basement_position(input) {
+            Some(position) => Ok(position.to_string()),
+            None => Err(AocError::NoSolution(String::from(
+                "Never reached the basement",
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_parenthesis() {
+        assert_eq!(count_parenthesis(&String::from("(())")), 0);
+        assert_eq!(count_parenthesis(&String::from("()()")), 0);
+        assert_eq!(count_parenthesis(&String::from("(((")), 3);
+        assert_eq!(count_parenthesis(&String::from("(()(()(")), 3);
+        assert_eq!(count_parenthesis(&String::from("))(((((")), 3);
+        assert_eq!(count_parenthesis(&String::from("())")), -1);
+        assert_eq!(count_parenthesis(&String::from("))(")), -1);
+        assert_eq!(count_parenthesis(&String::from(")))")), -3);
+        assert_eq!(count_parenthesis(&String::from(")())())")), -3);
+    }
+
+    #[test]
+    fn test_basement_position() {
+        assert_eq!(find_basement_position(&String::from(")")), Some(1));
+        assert_eq!(find_basement_position(&String::from("()())")), Some(5));
+        assert_eq!(find_basement_position(&String::from("(()")), None);
+    }
+}
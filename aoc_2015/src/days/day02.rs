@@ -0,0 +1,457 @@
+use aoc_common::parse::parse_lines;
+use aoc_common::{Answer, AocError, Solver};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+const HISTOGRAM_BUCKET_SIZE: u64 = 1000;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Rectangular {
+    pub height: u32,
+    pub length: u32,
+    pub width: u32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DimensionError {
+    FieldCount(usize),
+    InvalidNumber(String),
+    ZeroDimension,
+    Overflow,
+}
+
+impl fmt::Display for DimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DimensionError::FieldCount(count) => {
+                write!(f, "expected 3 dimensions, found {}", count)
+            }
+            DimensionError::InvalidNumber(field) => write!(f, "invalid dimension {:?}", field),
+            DimensionError::ZeroDimension => write!(f, "dimensions must be positive"),
+            DimensionError::Overflow => write!(f, "arithmetic overflow"),
+        }
+    }
+}
+
+impl FromStr for Rectangular {
+    type Err = DimensionError;
+
+    fn from_str(rect_str: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = rect_str.trim().split('x').collect();
+        if fields.len() != 3 {
+            return Err(DimensionError::FieldCount(fields.len()));
+        }
+        let mut dimensions = [0; 3];
+        for (dimension, field) in dimensions.iter_mut().zip(fields) {
+            *dimension = field
+                .parse::<u32>()
+                .map_err(|_| DimensionError::InvalidNumber(field.to_string()))?;
+            if *dimension == 0 {
+                return Err(DimensionError::ZeroDimension);
+            }
+        }
+        Ok(Rectangular {
+            height: dimensions[0],
+            length: dimensions[1],
+            width: dimensions[2],
+        })
+    }
+}
+
+fn checked(value: Option<u64>) -> Result<u64, DimensionError> {
+    value.ok_or(DimensionError::Overflow)
+}
+
+pub fn calculate_area(rect: &Rectangular) -> Result<u64, DimensionError> {
+    // 2*l*w + 2*w*h + 2*h*l
+    // u32*u32 always fits in u64, the doubling and the sums may not
+    let (height, length, width) = (rect.height as u64, rect.length as u64, rect.width as u64);
+    let faces = [length * width, width * height, height * length];
+    let min_area = *faces.iter().min().unwrap();
+    let mut total_area = min_area;
+    for face in faces {
+        total_area = checked(total_area.checked_add(checked(face.checked_mul(2))?))?;
+    }
+    Ok(total_area)
+}
+
+pub fn calculate_volume(rect: &Rectangular) -> Result<u64, DimensionError> {
+    let base = rect.height as u64 * rect.length as u64;
+    checked(base.checked_mul(rect.width as u64))
+}
+
+pub fn calculate_ribbon(rect: &Rectangular) -> Result<u64, DimensionError> {
+    let bow_size = calculate_volume(rect)?;
+    let mut dimensions = [rect.height as u64, rect.length as u64, rect.width as u64];
+    dimensions.sort(); // no sorted :(
+    let perimeter = 2 * dimensions[0] + 2 * dimensions[1];
+    checked(bow_size.checked_add(perimeter))
+}
+
+pub fn calculate_total<F>(rects: &[Rectangular], calculate: F) -> Result<u64, DimensionError>
+where
+    F: Fn(&Rectangular) -> Result<u64, DimensionError>,
+{
+    rects.iter().try_fold(0u64, |total, rect| {
+        checked(total.checked_add(calculate(rect)?))
+    })
+}
+
+pub fn describe_box(rect: &Rectangular) -> Result<String, DimensionError> {
+    Ok(format!(
+        "{}x{}x{}: paper {}, ribbon {}, volume {}",
+        rect.height,
+        rect.length,
+        rect.width,
+        calculate_area(rect)?,
+        calculate_ribbon(rect)?,
+        calculate_volume(rect)?
+    ))
+}
+
+// returns (smallest, largest) by volume. Ties keep the first box found
+pub fn find_extreme_boxes(
+    rects: &[Rectangular],
+) -> Result<Option<(&Rectangular, &Rectangular)>, DimensionError> {
+    let volumes = rects
+        .iter()
+        .map(calculate_volume)
+        .collect::<Result<Vec<u64>, DimensionError>>()?;
+    let smallest = volumes
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, volume)| **volume);
+    let largest = volumes
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|(_, volume)| **volume);
+    Ok(match (smallest, largest) {
+        (Some((smallest, _)), Some((largest, _))) => Some((&rects[smallest], &rects[largest])),
+        _ => None,
+    })
+}
+
+// keys are the lower bound of each bucket, so 0 holds volumes in 0..bucket_size
+pub fn volume_histogram(
+    rects: &[Rectangular],
+    bucket_size: u64,
+) -> Result<BTreeMap<u64, u32>, DimensionError> {
+    let mut histogram = BTreeMap::new();
+    for rect in rects {
+        let bucket = calculate_volume(rect)? / bucket_size * bucket_size;
+        *histogram.entry(bucket).or_insert(0) += 1;
+    }
+    Ok(histogram)
+}
+
+pub fn export_csv(rects: &[Rectangular]) -> Result<String, DimensionError> {
+    let mut output = String::from("height,length,width,paper,ribbon\n");
+    for rect in rects {
+        output.push_str(&format!(
+            "{},{},{},{},{}\n",
+            rect.height,
+            rect.length,
+            rect.width,
+            calculate_area(rect)?,
+            calculate_ribbon(rect)?
+        ));
+    }
+    Ok(output)
+}
+
+pub fn report(rects: &[Rectangular]) -> Result<String, DimensionError> {
+    let mut output = String::new();
+    for rect in rects {
+        output.push_str(&format!("{}\n", describe_box(rect)?));
+    }
+    if let Some((smallest, largest)) = find_extreme_boxes(rects)? {
+        output.push_str(&format!("smallest box: {}\n", describe_box(smallest)?));
+        output.push_str(&format!("largest box: {}\n", describe_box(largest)?));
+    }
+    output.push_str("volume histogram:\n");
+    for (bucket, count) in volume_histogram(rects, HISTOGRAM_BUCKET_SIZE)? {
+        output.push_str(&format!(
+            "{:>6}-{:<6} {:>4} {}\n",
+            bucket,
+            bucket.saturating_add(HISTOGRAM_BUCKET_SIZE - 1),
+            count,
+            "#".repeat(count as usize)
+        ));
+    }
+    Ok(output)
+}
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        let rects = parse_lines(input)?;
+        calculate_total(&rects, calculate_area)
+            .map(|x| x.to_string())
+            .map_err(|x| AocError::Parse(x.to_string()))
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        let rects = parse_lines(input)?;
+        calculate_total(&rects, calculate_ribbon)
+            .map(|x| x.to_string())
+            .map_err(|x| AocError::Parse(x.to_string()))
+    }
+
+    // `report` prints a per-box breakdown, `csv` dumps every box for external tools
+    fn run_mode(&self, input: &str, args: &[String]) -> Answer {
+        let rects = parse_lines(input)?;
+        let output = match args.first().map(|x| x.as_str()) {
+            Some("report") => report(&rects),
+            Some("csv") => export_csv(&rects),
+            _ => return Err(AocError::Usage(String::from("2 report|csv"))),
+        };
+        output.map_err(|x| AocError::Parse(x.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_area() {
+        assert_eq!(
+            calculate_area(&Rectangular {
+                height: 2,
+                length: 3,
+                width: 4
+            }),
+            Ok(58)
+        );
+        assert_eq!(
+            calculate_area(&Rectangular {
+                height: 1,
+                length: 1,
+                width: 10
+            }),
+            Ok(43)
+        );
+        let max = u32::MAX;
+        assert_eq!(
+            calculate_area(&Rectangular {
+                height: max,
+                length: max,
+                width: max
+            }),
+            Err(DimensionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!("1x1x10".parse::<Rectangular>().unwrap().width, 10);
+        assert_eq!(
+            "2x3x4".parse(),
+            Ok(Rectangular {
+                height: 2,
+                length: 3,
+                width: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_malformed_line() {
+        assert_eq!(
+            "".parse::<Rectangular>(),
+            Err(DimensionError::FieldCount(1))
+        );
+        assert_eq!(
+            "1xx3".parse::<Rectangular>(),
+            Err(DimensionError::InvalidNumber(String::from("")))
+        );
+        assert_eq!(
+            "1x2".parse::<Rectangular>(),
+            Err(DimensionError::FieldCount(2))
+        );
+        assert_eq!(
+            "1x2x3x4".parse::<Rectangular>(),
+            Err(DimensionError::FieldCount(4))
+        );
+        assert_eq!(
+            "1x-2x3".parse::<Rectangular>(),
+            Err(DimensionError::InvalidNumber(String::from("-2")))
+        );
+        assert_eq!(
+            "1xax3".parse::<Rectangular>(),
+            Err(DimensionError::InvalidNumber(String::from("a")))
+        );
+        assert_eq!(
+            "1x0x3".parse::<Rectangular>(),
+            Err(DimensionError::ZeroDimension)
+        );
+        assert_eq!(
+            "1x99999999999x3".parse::<Rectangular>(),
+            Err(DimensionError::InvalidNumber(String::from("99999999999")))
+        );
+    }
+
+    #[test]
+    fn test_calculate_ribbon() {
+        assert_eq!(
+            calculate_ribbon(&Rectangular {
+                height: 2,
+                length: 3,
+                width: 4
+            }),
+            Ok(34)
+        );
+        assert_eq!(
+            calculate_ribbon(&Rectangular {
+                height: 1,
+                length: 1,
+                width: 10
+            }),
+            Ok(14)
+        );
+        let max = u32::MAX;
+        assert_eq!(
+            calculate_ribbon(&Rectangular {
+                height: max,
+                length: max,
+                width: max
+            }),
+            Err(DimensionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_calculate_volume() {
+        assert_eq!(
+            calculate_volume(&Rectangular {
+                height: 2,
+                length: 3,
+                width: 4
+            }),
+            Ok(24)
+        );
+        assert_eq!(
+            calculate_volume(&Rectangular {
+                height: 1,
+                length: 1,
+                width: 10
+            }),
+            Ok(10)
+        );
+        // overflows u32 but fits once widened
+        assert_eq!(
+            calculate_volume(&Rectangular {
+                height: 65536,
+                length: 65536,
+                width: 2
+            }),
+            Ok(8589934592)
+        );
+        let max = u32::MAX;
+        assert_eq!(
+            calculate_volume(&Rectangular {
+                height: max,
+                length: max,
+                width: max
+            }),
+            Err(DimensionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_calculate_total() {
+        let rects = vec![
+            Rectangular {
+                height: 2,
+                length: 3,
+                width: 4,
+            },
+            Rectangular {
+                height: 1,
+                length: 1,
+                width: 10,
+            },
+        ];
+        assert_eq!(calculate_total(&rects, calculate_area), Ok(101));
+        assert_eq!(calculate_total(&rects, calculate_ribbon), Ok(48));
+        assert_eq!(calculate_total(&[], calculate_area), Ok(0));
+    }
+
+    #[test]
+    fn test_find_extreme_boxes() {
+        let rects = vec![
+            Rectangular {
+                height: 2,
+                length: 3,
+                width: 4,
+            },
+            Rectangular {
+                height: 1,
+                length: 1,
+                width: 10,
+            },
+            Rectangular {
+                height: 5,
+                length: 5,
+                width: 5,
+            },
+        ];
+        let (smallest, largest) = find_extreme_boxes(&rects).unwrap().unwrap();
+        assert_eq!(smallest, &rects[1]);
+        assert_eq!(largest, &rects[2]);
+        assert_eq!(find_extreme_boxes(&[]), Ok(None));
+    }
+
+    #[test]
+    fn test_volume_histogram() {
+        let rects = vec![
+            Rectangular {
+                height: 2,
+                length: 3,
+                width: 4,
+            },
+            Rectangular {
+                height: 1,
+                length: 1,
+                width: 10,
+            },
+            Rectangular {
+                height: 5,
+                length: 5,
+                width: 5,
+            },
+        ];
+        let histogram = volume_histogram(&rects, 20).unwrap();
+        assert_eq!(histogram.get(&0), Some(&1));
+        assert_eq!(histogram.get(&20), Some(&1));
+        assert_eq!(histogram.get(&120), Some(&1));
+        assert_eq!(histogram.len(), 3);
+    }
+
+    #[test]
+    fn test_export_csv() {
+        let rects = vec![
+            Rectangular {
+                height: 2,
+                length: 3,
+                width: 4,
+            },
+            Rectangular {
+                height: 1,
+                length: 1,
+                width: 10,
+            },
+        ];
+        assert_eq!(
+            export_csv(&rects).unwrap(),
+            "height,length,width,paper,ribbon\n2,3,4,58,34\n1,1,10,43,14\n"
+        );
+    }
+}
@@ -1,12 +1,8 @@
+use aoc_common::{Answer, Solver};
 use std::collections::HashSet;
 use std::convert::From;
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
 
-struct Location(
-    i32, i32
-);
+struct Location(i32, i32);
 
 impl From<&Location> for String {
     fn from(item: &Location) -> Self {
@@ -14,17 +10,17 @@ impl From<&Location> for String {
     }
 }
 
-fn calculate_location(current_location: &Location, direction: char) ->  Location {
+fn calculate_location(current_location: &Location, direction: char) -> Location {
     match direction {
-        '<' => Location((&current_location).0 - 1, (&current_location).1),
-        '^' => Location((&current_location).0, (&current_location).1 + 1),
-        '>' => Location((&current_location).0 + 1, (&current_location).1),
-        'v' => Location((&current_location).0, (&current_location).1 - 1),
-        _ => Location((&current_location).0, (&current_location).1),
+        '<' => Location(current_location.0 - 1, current_location.1),
+        '^' => Location(current_location.0, current_location.1 + 1),
+        '>' => Location(current_location.0 + 1, current_location.1),
+        'v' => Location(current_location.0, current_location.1 - 1),
+        _ => Location(current_location.0, current_location.1),
     }
 }
 
-fn calculate_visits(movements: &String) -> u32{
+pub fn calculate_visits(movements: &str) -> u32 {
     let mut visited: HashSet<String> = HashSet::new();
     let mut current_location = Location(0, 0);
     visited.insert(String::from(&current_location));
@@ -37,7 +33,7 @@ fn calculate_visits(movements: &String) -> u32{
     visited.len() as u32
 }
 
-fn calculate_visits_v2(movements: &String) -> u32 {
+pub fn calculate_visits_v2(movements: &str) -> u32 {
     let mut visited: HashSet<String> = HashSet::new();
     let mut current_location_santa = Location(0, 0);
     let mut current_location_robo = Location(0, 0);
@@ -59,22 +55,20 @@ fn calculate_visits_v2(movements: &String) -> u32 {
     visited.len() as u32
 }
 
-fn main(){
-    let path = Path::new("./input/day3.txt");
-    let display = path.display();
-    let mut file = match File::open(&path) {
-        Err(why) => panic!("couldn't open {}: {}", display, why),
-        Ok(file) => file,
-    };
-
-    let mut test_str = String::new();
-    match file.read_to_string(&mut test_str) {
-        Err(why) => panic!("couldn't read {}: {}", display, why),
-        Ok(_) => (),
+pub struct Day03;
+
+impl Solver for Day03 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        Ok(calculate_visits(input).to_string())
     }
 
-    println!("visits for santa alone: {}", calculate_visits(&test_str));
-    println!("visits for santa with robo help: {}", calculate_visits_v2(&test_str));
+    fn part2(&self, input: &str) -> Answer {
+        Ok(calculate_visits_v2(input).to_string())
+    }
 }
 
 #[cfg(test)]
@@ -82,16 +76,16 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_calculate_visits(){
+    fn test_calculate_visits() {
         assert_eq!(calculate_visits(&String::from(">")), 2);
         assert_eq!(calculate_visits(&String::from("^>v<")), 4);
         assert_eq!(calculate_visits(&String::from("^v^v^v^v^v")), 2);
     }
 
     #[test]
-    fn test_calculate_visits_v2(){
-        assert_eq!(calculate_visits_v2(&Strings::from("^v")), 3);
-        assert_eq!(calculate_visits_v2(&Strings::from("^>v<")), 3);
-        assert_eq!(calculate_visits_v2(&Strings::from("^v^v^v^v^v")), 11);
+    fn test_calculate_visits_v2() {
+        assert_eq!(calculate_visits_v2(&String::from("^v")), 3);
+        assert_eq!(calculate_visits_v2(&String::from("^>v<")), 3);
+        assert_eq!(calculate_visits_v2(&String::from("^v^v^v^v^v")), 11);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,84 @@
+use aoc_common::{Answer, AocError, Budget, Solver};
+use md5::{Digest, Md5};
+
+fn check_signature(target: &[u8], zeroes: usize) -> bool {
+    // this is a list of u8 (bytes), two hex digits per byte. Whole zero bytes
+    // cover pairs of digits, an odd count also needs the next byte below 16 (00001111)
+    let full_bytes = zeroes / 2;
+    target[..full_bytes].iter().all(|x| *x == 0)
+        && (zeroes.is_multiple_of(2) || target[full_bytes] < 16)
+}
+
+// hashing is slow enough that the budget only needs a look now and then
+const CHECK_EVERY: i32 = 4096;
+
+pub fn mine_adventcoin(secret_key: &str, zeroes: usize, budget: &Budget) -> Result<i32, AocError> {
+    let mut decimal = 0;
+    loop {
+        if decimal % CHECK_EVERY == 0 {
+            budget.check()?;
+        }
+        let to_test = format!("{}{}", secret_key, decimal);
+        let mut hasher = Md5::new();
+        hasher.input(to_test.as_bytes()); //ewwww
+        let digest = hasher.result();
+        if check_signature(&digest, zeroes) {
+            return Ok(decimal);
+        }
+        decimal += 1;
+    }
+}
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        self.part1_within(input, &Budget::unlimited())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        self.part2_within(input, &Budget::unlimited())
+    }
+
+    fn part1_within(&self, input: &str, budget: &Budget) -> Answer {
+        mine_adventcoin(input.trim(), 5, budget).map(|x| x.to_string())
+    }
+
+    fn part2_within(&self, input: &str, budget: &Budget) -> Answer {
+        mine_adventcoin(input.trim(), 6, budget).map(|x| x.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_signature() {
+        assert!(check_signature(&[0, 0, 15, 255], 5));
+        assert!(!check_signature(&[0, 0, 16, 0], 5));
+        assert!(check_signature(&[0, 0, 0, 255], 6));
+        assert!(!check_signature(&[0, 0, 15, 0], 6));
+    }
+
+    #[test]
+    fn test_find_hash() {
+        let budget = Budget::unlimited();
+        assert_eq!(mine_adventcoin("abcdef", 5, &budget), Ok(609043));
+        assert_eq!(mine_adventcoin("pqrstuv", 5, &budget), Ok(1048970));
+    }
+
+    #[test]
+    fn test_mine_adventcoin_gives_up() {
+        let budget = Budget::unlimited();
+        budget.cancel();
+        assert!(matches!(
+            mine_adventcoin("abcdef", 32, &budget),
+            Err(AocError::Timeout(_))
+        ));
+    }
+}
@@ -1,10 +1,7 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+use aoc_common::{Answer, Solver};
 use itertools::Itertools;
 
-
-fn check_nice_string(to_test: String) -> bool {
+pub fn check_nice_string(to_test: String) -> bool {
     // A nice string is one with all of the following properties:
     // It contains at least three vowels (aeiou only), like aei, xazegov, or aeiouaeiouaeiou.
     // It contains at least one letter that appears twice in a row, like xx, abcdde (dd), or aabbccdd (aa, bb, cc, or dd).
@@ -16,22 +13,18 @@ fn check_nice_string(to_test: String) -> bool {
     let mut repeated_char = false;
     let mut found_forbidden = false;
 
-    for (key, (a, b)) in to_test.chars().tuple_windows().enumerate(){
+    for (key, (a, b)) in to_test.chars().tuple_windows().enumerate() {
         // Check for vowels
-        if key == 0 {
-            if vowels.contains(&a) {
-                found_vowels.push(a);
-            }
+        if key == 0 && vowels.contains(&a) {
+            found_vowels.push(a);
         }
         if vowels.contains(&b) {
             found_vowels.push(b);
         }
 
         // check for repeated characters
-        if !repeated_char {
-            if a == b {
-                repeated_char = true;
-            }
+        if !repeated_char && a == b {
+            repeated_char = true;
         }
 
         // check for forbidden combinations
@@ -46,7 +39,7 @@ fn check_nice_string(to_test: String) -> bool {
     found_vowels.len() >= 3 && repeated_char && !found_forbidden
 }
 
-fn check_nice_string_v2(to_test: String) -> bool {
+pub fn check_nice_string_v2(to_test: String) -> bool {
     // Now, a nice string is one with all of the following properties:
     // It contains a pair of any two letters that appears at least twice in the string without overlapping,
     // like xyxy (xy) or aabcdefgaa (aa), but not like aaa (aa, but it overlaps).
@@ -54,15 +47,15 @@ fn check_nice_string_v2(to_test: String) -> bool {
     let mut found_match = false;
     let mut found_overlap = true;
     let mut found_repeated_char = false;
-    for (key_1, (a, b)) in to_test.chars().tuple_windows().enumerate(){
+    for (key_1, (a, b)) in to_test.chars().tuple_windows().enumerate() {
         let to_test_with_offset = &to_test[key_1 + 1..];
-        for (key_2, (c, d)) in to_test_with_offset.chars().tuple_windows().enumerate(){
+        for (key_2, (c, d)) in to_test_with_offset.chars().tuple_windows().enumerate() {
             // Check for matches and overlaps in the whole string
             // key_2 starts 1 element after key_1. If key_2 is zero, it means the windows are overlapping.
             // println!("({},{}) - ({}, {}) - ({}, {})", a, b, c, d, key_1, key_2);
             if (a, b) == (c, d) {
                 found_match = true;
-                if key_2 != 0 { 
+                if key_2 != 0 {
                     found_overlap = false;
                 }
             }
@@ -71,33 +64,29 @@ fn check_nice_string_v2(to_test: String) -> bool {
             if key_2 == 0 && a == d {
                 found_repeated_char = true;
             }
-
         }
     }
     found_match && !found_overlap && found_repeated_char
 }
 
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where P: AsRef<Path>, {
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+pub fn count_nice_strings(strings: &str, check: fn(String) -> bool) -> usize {
+    strings.lines().filter(|x| check(x.to_string())).count()
 }
 
-fn main() {
-    let mut checked_strings: Vec<bool> = Vec::new();
-    if let Ok(lines) = read_lines("./input/day5.txt") {
-        // Consumes the iterator, returns an (Optional) String
-        for line in lines {
-            if let Ok(value) = line {
-                // checked_strings.push(check_nice_string(value));
-                checked_strings.push(check_nice_string_v2(value));
-            }
-        }
+pub struct Day05;
+
+impl Solver for Day05 {
+    fn day(&self) -> u8 {
+        5
     }
 
-    let nice_strings = checked_strings.into_iter().map(|x|if x { 1 } else { 0 }).reduce(|a,b|a+b).unwrap();
-    println!("{} nice strings were found", nice_strings);
+    fn part1(&self, input: &str) -> Answer {
+        Ok(count_nice_strings(input, check_nice_string).to_string())
+    }
 
+    fn part2(&self, input: &str) -> Answer {
+        Ok(count_nice_strings(input, check_nice_string_v2).to_string())
+    }
 }
 
 #[cfg(test)]
@@ -124,4 +113,4 @@ mod tests {
         assert!(!check_nice_string_v2(String::from("ieodomkazucvgmuy")));
         assert!(!check_nice_string_v2(String::from("aaa")));
     }
-}
\ No newline at end of file
+}
@@ -1,46 +1,41 @@
+use aoc_common::{Answer, Solver};
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::default::Default;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
 
-struct Grid(Box<[[u8; 1000]; 1000]>);
+use aoc_common::grid::Grid;
 
-struct Rectangle {
-    bottom_left: Point,
-    top_right: Point,
+const GRID_SIZE: usize = 1000;
+
+pub struct Rectangle {
+    pub bottom_left: Point,
+    pub top_right: Point,
 }
 
-struct Point {
-    x: usize,
-    y: usize,
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
 }
 
 #[derive(Debug, PartialEq, Eq)]
-enum Operation {
+pub enum Operation {
     On,
     Off,
     Toggle,
 }
 
-impl Default for Grid {
-    fn default() -> Self {
-        Grid(Box::new([[0; 1000]; 1000]))
-    }
+pub fn new_light_grid() -> Grid<u8> {
+    Grid::new(GRID_SIZE, GRID_SIZE, 0)
 }
 
-fn count_grid_on(grid: &mut Grid) -> u32 {
+pub fn count_grid_on(grid: &mut Grid<u8>) -> u32 {
     let mut counter: u32 = 0;
-    for element in grid.0.iter() {
-        for inner_element in element.iter() {
-            counter += *inner_element as u32;
-        }
+    for element in grid.cells() {
+        counter += *element as u32;
     }
     counter
 }
 
-fn turn_grid_to_value(grid: &mut Grid, rectangle: &Rectangle, operation: Operation) {
+pub fn turn_grid_to_value(grid: &mut Grid<u8>, rectangle: &Rectangle, operation: Operation) {
     for x in rectangle.bottom_left.x..=rectangle.top_right.x {
         for y in rectangle.bottom_left.y..=rectangle.top_right.y {
             let delta: i32 = match operation {
@@ -48,25 +43,38 @@ fn turn_grid_to_value(grid: &mut Grid, rectangle: &Rectangle, operation: Operati
                 Operation::Off => -1,
                 Operation::Toggle => 2,
             };
-            let value: u8 = if (grid.0[x][y] as i32) + delta >= 0 {
-                (grid.0[x][y] as i32 + delta) as u8
+            let value: u8 = if (grid.get(x, y) as i32) + delta >= 0 {
+                (grid.get(x, y) as i32 + delta) as u8
             } else {
                 0
             };
-            grid.0[x][y] = value;
+            grid.set(x, y, value);
+        }
+    }
+}
+
+// first reading of the instructions: lights are just on or off
+pub fn switch_grid(grid: &mut Grid<u8>, rectangle: &Rectangle, operation: Operation) {
+    for x in rectangle.bottom_left.x..=rectangle.top_right.x {
+        for y in rectangle.bottom_left.y..=rectangle.top_right.y {
+            let value = match operation {
+                Operation::On => 1,
+                Operation::Off => 0,
+                Operation::Toggle => 1 - grid.get(x, y),
+            };
+            grid.set(x, y, value);
         }
     }
 }
 
-fn parse_line(instruction: String) -> (Operation, Rectangle) {
+pub fn parse_line(instruction: String) -> (Operation, Rectangle) {
     // compiled a single time
     lazy_static! {
         static ref RE: Regex =
             Regex::new(r"(turn on|turn off|toggle) ([0-9]*),([0-9]*) through ([0-9]*),([0-9]*)")
                 .unwrap();
     }
-    // need to consume the iterator, even if we expect a single match
-    for capture in RE.captures_iter(&instruction[..]) {
+    if let Some(capture) = RE.captures(&instruction[..]) {
         let operation = match &capture[1] {
             "turn on" => Operation::On,
             "turn off" => Operation::Off,
@@ -87,26 +95,32 @@ fn parse_line(instruction: String) -> (Operation, Rectangle) {
     panic!()
 }
 
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+pub fn follow_instructions(
+    instructions: &str,
+    apply: fn(&mut Grid<u8>, &Rectangle, Operation),
+) -> u32 {
+    let mut grid = new_light_grid();
+    for line in instructions.lines() {
+        let (operation, rectangle) = parse_line(line.to_string());
+        apply(&mut grid, &rectangle, operation);
+    }
+    count_grid_on(&mut grid)
 }
 
-fn main() {
-    let mut grid: Grid = Default::default();
-    if let Ok(lines) = read_lines("./input/day6.txt") {
-        // Consumes the iterator, returns an (Optional) String
-        for line in lines {
-            if let Ok(value) = line {
-                let (operation, rectangle) = parse_line(value);
-                turn_grid_to_value(&mut grid, &rectangle, operation);
-            }
-        }
+pub struct Day06;
+
+impl Solver for Day06 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        Ok(follow_instructions(input, switch_grid).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        Ok(follow_instructions(input, turn_grid_to_value).to_string())
     }
-    println!("Lights on: {}", count_grid_on(&mut grid));
 }
 
 #[cfg(test)]
@@ -115,17 +129,14 @@ mod tests {
 
     #[test]
     fn test_count_grid_on() {
-        assert_eq!(count_grid_on(&mut Default::default()), 0);
-        assert_eq!(
-            count_grid_on(&mut Grid(Box::new([[1; 1000]; 1000]))),
-            1000000
-        );
+        assert_eq!(count_grid_on(&mut new_light_grid()), 0);
+        assert_eq!(count_grid_on(&mut Grid::new(1000, 1000, 1)), 1000000);
     }
 
     #[test]
     fn test_turn_grid_to_value() {
         // testing with side-effects...bad. Returning a new grid with the new values would be cleaner...and more expensive
-        let mut grid = Default::default();
+        let mut grid = new_light_grid();
         turn_grid_to_value(
             &mut grid,
             &Rectangle {
@@ -181,4 +192,36 @@ mod tests {
         assert_eq!(rectangle.top_right.x, 808);
         assert_eq!(rectangle.top_right.y, 802);
     }
+
+    #[test]
+    fn test_switch_grid() {
+        let mut grid = new_light_grid();
+        switch_grid(
+            &mut grid,
+            &Rectangle {
+                bottom_left: Point { x: 0, y: 0 },
+                top_right: Point { x: 999, y: 999 },
+            },
+            Operation::On,
+        );
+        assert_eq!(count_grid_on(&mut grid), 1000000);
+        switch_grid(
+            &mut grid,
+            &Rectangle {
+                bottom_left: Point { x: 0, y: 0 },
+                top_right: Point { x: 999, y: 0 },
+            },
+            Operation::Toggle,
+        );
+        assert_eq!(count_grid_on(&mut grid), 999000);
+        switch_grid(
+            &mut grid,
+            &Rectangle {
+                bottom_left: Point { x: 499, y: 499 },
+                top_right: Point { x: 500, y: 500 },
+            },
+            Operation::Off,
+        );
+        assert_eq!(count_grid_on(&mut grid), 998996);
+    }
 }
@@ -1,11 +1,12 @@
+use aoc_common::{Answer, AocError, Budget, Solver};
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::HashMap;
 
-const FILE_TEXT: &str = include_str!("../input/day7.txt");
-
+// named after the gate mnemonics used by the instructions
+#[allow(clippy::upper_case_acronyms)]
 #[derive(PartialEq, Debug)]
-enum Operation {
+pub enum Operation {
     NOOP,
     NOT,
     AND,
@@ -16,14 +17,14 @@ enum Operation {
 }
 
 impl Operation {
-    fn can_run(&self, wire_a: Option<u16>, wire_b: Option<u16>) -> bool {
+    pub fn can_run(&self, wire_a: Option<u16>, wire_b: Option<u16>) -> bool {
         match *self {
-            Operation::NOOP | Operation::NOT => wire_a != None,
-            _ => wire_a != None && wire_b != None,
+            Operation::NOOP | Operation::NOT => wire_a.is_some(),
+            _ => wire_a.is_some() && wire_b.is_some(),
         }
     }
 
-    fn run(&self, wire_a: Option<u16>, wire_b: Option<u16>) -> Option<u16> {
+    pub fn run(&self, wire_a: Option<u16>, wire_b: Option<u16>) -> Option<u16> {
         if self.can_run(wire_a, wire_b) {
             Some(match *self {
                 Operation::NOOP => wire_a.unwrap(),
@@ -40,7 +41,7 @@ impl Operation {
     }
 }
 
-fn eval_instruction(
+pub fn eval_instruction(
     instruction: &str,
     wires: &HashMap<String, u16>,
 ) -> Option<(String, Option<u16>)> {
@@ -65,7 +66,7 @@ fn eval_instruction(
                 operation = Operation::NOOP;
             }
             wire_a = cap[2].parse::<u16>().ok();
-            if wire_a == None {
+            if wire_a.is_none() {
                 if let Some(wire_a_resolved) = wires.get(&cap[2]) {
                     wire_a = Some(*wire_a_resolved);
                 }
@@ -84,13 +85,13 @@ fn eval_instruction(
             };
 
             wire_a = cap[1].parse::<u16>().ok();
-            if wire_a == None {
+            if wire_a.is_none() {
                 if let Some(wire_a_resolved) = wires.get(&cap[1]) {
                     wire_a = Some(*wire_a_resolved);
                 }
             }
             wire_b = cap[3].parse::<u16>().ok();
-            if wire_b == None {
+            if wire_b.is_none() {
                 if let Some(wire_b_resolved) = wires.get(&cap[3]) {
                     wire_b = Some(*wire_b_resolved);
                 }
@@ -103,38 +104,83 @@ fn eval_instruction(
     Some((target, operation.run(wire_a, wire_b)))
 }
 
-fn wire_circuit(instructions: &str) -> HashMap<String, u16> {
+// Resolves wires pass by pass. A pass that resolves nothing means the rest
+// never will, `budget` is only the backstop
+pub fn wire_circuit(instructions: &str, budget: &Budget) -> Result<HashMap<String, u16>, AocError> {
     let mut wires: HashMap<String, u16> = HashMap::new();
     let mut pending_instructions = String::from("");
     let mut running_instructions = instructions.to_string();
     loop {
+        budget.check()?;
+        let resolved = wires.len();
         for instruction in running_instructions.lines() {
-            if let Some((key, output)) = eval_instruction(instruction, &wires) {
-                if let Some(value) = output {
-                    if let Some(value) = wires.get(&key) {
-                        println!("Already exists {}", value);
-                    }
-                    wires.insert(key, value);
-                } else {
-                    pending_instructions = format!("{}\n{}", pending_instructions, &instruction);
+            if let Some((key, Some(value))) = eval_instruction(instruction, &wires) {
+                if wires.contains_key(&key) {
+                    return Err(AocError::Parse(format!("wire {} is driven twice", key)));
                 }
+                wires.insert(key, value);
             } else {
                 pending_instructions = format!("{}\n{}", pending_instructions, &instruction);
             }
         }
         if pending_instructions.is_empty() {
             break;
+        } else if wires.len() == resolved {
+            return Err(AocError::NoSolution(format!(
+                "{} instructions can't be resolved",
+                pending_instructions.trim().lines().count()
+            )));
         } else {
             running_instructions = pending_instructions.trim().to_string();
             pending_instructions = String::from("");
         }
     }
+    Ok(wires)
+}
+
+// drops whatever drives `wire` and feeds it `value` instead
+pub fn override_wire(instructions: &str, wire: &str, value: u16) -> String {
+    let target = format!("-> {}", wire);
+    let mut overridden: Vec<String> = instructions
+        .lines()
+        .filter(|x| !x.trim().is_empty() && !x.trim_end().ends_with(&target))
+        .map(|x| x.to_string())
+        .collect();
+    overridden.push(format!("{} -> {}", value, wire));
+    overridden.join("\n")
+}
+
+fn read_wire(wires: &HashMap<String, u16>, wire: &str) -> Result<u16, AocError> {
     wires
+        .get(wire)
+        .copied()
+        .ok_or_else(|| AocError::NoSolution(format!("wire {} has no signal", wire)))
 }
 
-fn main() {
-    let wires = wire_circuit(FILE_TEXT);
-    println!("{:?}", wires["a"]);
+pub struct Day07;
+
+impl Solver for Day07 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        self.part1_within(input, &Budget::unlimited())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        self.part2_within(input, &Budget::unlimited())
+    }
+
+    fn part1_within(&self, input: &str, budget: &Budget) -> Answer {
+        read_wire(&wire_circuit(input, budget)?, "a").map(|x| x.to_string())
+    }
+
+    fn part2_within(&self, input: &str, budget: &Budget) -> Answer {
+        let a = read_wire(&wire_circuit(input, budget)?, "a")?;
+        let overridden = wire_circuit(&override_wire(input, "b", a), budget)?;
+        read_wire(&overridden, "a").map(|x| x.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -151,10 +197,35 @@ mod tests {
                             y RSHIFT 2 -> g
                             NOT x -> h
                             NOT y -> i";
-        let wires = wire_circuit(instructions);
+        let wires = wire_circuit(instructions, &Budget::unlimited()).unwrap();
         assert_eq!(wires["i"], 65079);
     }
 
+    #[test]
+    fn test_wire_circuit_gives_up() {
+        // y is never driven, so x can't be resolved
+        assert_eq!(
+            wire_circuit("y -> x\n1 -> z\nx OR z -> w", &Budget::unlimited()),
+            Err(AocError::NoSolution(String::from(
+                "2 instructions can't be resolved"
+            )))
+        );
+        let budget = Budget::unlimited();
+        budget.cancel();
+        assert!(matches!(
+            wire_circuit("1 -> z", &budget),
+            Err(AocError::Timeout(_))
+        ));
+    }
+
+    #[test]
+    fn test_wire_driven_twice() {
+        assert_eq!(
+            wire_circuit("1 -> x\n2 -> x", &Budget::unlimited()),
+            Err(AocError::Parse(String::from("wire x is driven twice")))
+        );
+    }
+
     #[test]
     fn test_eval_instruction() {
         let wires = HashMap::new();
@@ -219,4 +290,15 @@ mod tests {
         );
         assert_eq!(Operation::RSHIFT.run(Some(0b111111), Some(2)), Some(0b1111));
     }
+
+    #[test]
+    fn test_override_wire() {
+        let instructions = "123 -> x\nx AND y -> b\nb OR x -> a";
+        assert_eq!(
+            override_wire(instructions, "b", 7),
+            "123 -> x\nb OR x -> a\n7 -> b"
+        );
+        let wires = wire_circuit(&override_wire(instructions, "b", 7), &Budget::unlimited());
+        assert_eq!(wires.unwrap()["a"], 127);
+    }
 }
@@ -1,16 +1,14 @@
-const FILE_TEXT: &str = include_str!("../input/day8.txt");
+use aoc_common::{Answer, Solver};
 
-fn count_characters(line: &str) -> u32 {
+pub fn count_characters(line: &str) -> u32 {
     // very lazy hardcoded approach
     let count_chars = line.len();
     let mut count_escaped: i32 = 0;
     let mut previous = 'x';
     for current_char in line.chars() {
         count_escaped += 1;
-        if current_char == 'x' {
-            if previous == '\\' {
-                count_escaped -= 3;
-            }
+        if current_char == 'x' && previous == '\\' {
+            count_escaped -= 3;
         }
         if current_char == '\\' && previous == '\\' {
             count_escaped -= 1;
@@ -25,7 +23,7 @@ fn count_characters(line: &str) -> u32 {
     count_chars as u32 - count_escaped as u32
 }
 
-fn encode_line(line: &str) -> u32 {
+pub fn encode_line(line: &str) -> u32 {
     let mut count_encoded = 2;
     for current_char in line.chars() {
         count_encoded += 1;
@@ -36,25 +34,28 @@ fn encode_line(line: &str) -> u32 {
     count_encoded - line.len() as u32
 }
 
-fn sum_lines(strings: &str) -> u32 {
-    strings
-        .lines()
-        .map(|x| count_characters(x))
-        .reduce(|a, b| a + b)
-        .unwrap()
+pub fn sum_lines(strings: &str) -> u32 {
+    strings.lines().map(count_characters).sum()
 }
 
-fn sum_lines_2(strings: &str) -> u32 {
-    strings
-        .lines()
-        .map(|x| encode_line(x))
-        .reduce(|a, b| a + b)
-        .unwrap()
+pub fn sum_lines_2(strings: &str) -> u32 {
+    strings.lines().map(encode_line).sum()
 }
 
-fn main() {
-    let output = sum_lines_2(FILE_TEXT);
-    println!("{}", output);
+pub struct Day08;
+
+impl Solver for Day08 {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        Ok(sum_lines(input).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        Ok(sum_lines_2(input).to_string())
+    }
 }
 
 #[cfg(test)]
@@ -1,17 +1,11 @@
-use itertools::Itertools;
+use aoc_common::{Answer, Solver};
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::cmp;
 use std::collections::{HashMap, HashSet};
 
-const FILE_TEXT: &str = include_str!("../input/day9.txt");
+use crate::graph::{self, Direction, Edge, RouteShape};
 
-#[derive(Eq, PartialEq, Debug)]
-struct Distance {
-    origin: String,
-    destination: String,
-    distance: u32,
-}
+pub type Distance = Edge<u32>;
 
 impl From<&str> for Distance {
     fn from(data: &str) -> Self {
@@ -19,18 +13,18 @@ impl From<&str> for Distance {
             static ref DISTANCE_RE: Regex =
                 Regex::new(r"^([a-zA-Z]*)\sto\s([a-zA-Z]*)\s=\s([0-9]*)$").unwrap();
         }
-        for cap in DISTANCE_RE.captures_iter(data) {
+        if let Some(cap) = DISTANCE_RE.captures(data) {
             return Distance {
                 origin: cap[1].to_string(),
                 destination: cap[2].to_string(),
-                distance: cap[3].parse::<u32>().unwrap(),
+                weight: cap[3].parse::<u32>().unwrap(),
             };
         }
         panic!()
     }
 }
 
-fn parse_list_into_distance(distances_str: &str) -> Vec<Distance> {
+pub fn parse_list_into_distance(distances_str: &str) -> Vec<Distance> {
     let mut distances: Vec<Distance> = Vec::new();
     for line in distances_str.lines() {
         distances.push(Distance::from(line));
@@ -38,74 +32,52 @@ fn parse_list_into_distance(distances_str: &str) -> Vec<Distance> {
     distances
 }
 
-fn construct_distance_table(distances: &Vec<Distance>) -> HashMap<(String, String), u32> {
-    let mut distances_map = HashMap::new();
-    for distance in distances {
-        distances_map.insert(
-            (distance.origin.clone(), distance.destination.clone()),
-            distance.distance,
-        );
-        distances_map.insert(
-            (distance.destination.clone(), distance.origin.clone()),
-            distance.distance,
-        );
-    }
-    distances_map
+pub fn construct_distance_table(distances: &[Distance]) -> HashMap<(String, String), u32> {
+    graph::construct_weight_table(distances, Direction::Undirected)
 }
 
-fn get_all_locations(distances: &Vec<Distance>) -> HashSet<String> {
-    let mut cities = HashSet::new();
-    for distance in distances {
-        cities.insert(distance.origin.clone());
-        cities.insert(distance.destination.clone());
-    }
-    cities
+pub fn get_all_locations(distances: &[Distance]) -> HashSet<String> {
+    graph::get_all_nodes(distances)
 }
 
-fn calculate_shortest_distance(
+pub fn calculate_shortest_distance(
     cities: &HashSet<String>,
     distances_map: &HashMap<(String, String), u32>,
 ) -> u32 {
-    let mut min_distance = 0;
-    for route in cities.iter().permutations(cities.len()) {
-        let mut distance = 0;
-        for (city_a, city_b) in route.iter().tuple_windows() {
-            distance += distances_map[&(String::from(*city_a), String::from(*city_b))];
-        }
-        if min_distance == 0 {
-            min_distance = distance;
-        } else {
-            min_distance = cmp::min(min_distance, distance);
-        }
-    }
-    min_distance
+    graph::route_scores(cities, distances_map, RouteShape::Path)
+        .min()
+        .unwrap_or(0)
 }
 
-fn calculate_longest_distance(
+pub fn calculate_longest_distance(
     cities: &HashSet<String>,
     distances_map: &HashMap<(String, String), u32>,
 ) -> u32 {
-    let mut max_distance = 0;
-    for route in cities.iter().permutations(cities.len()) {
-        let mut distance = 0;
-        for (city_a, city_b) in route.iter().tuple_windows() {
-            distance += distances_map[&(String::from(*city_a), String::from(*city_b))];
-        }
-        max_distance = cmp::max(max_distance, distance);
-    }
-    max_distance
+    graph::route_scores(cities, distances_map, RouteShape::Path)
+        .max()
+        .unwrap_or(0)
 }
 
-fn main() {
-    let distances = parse_list_into_distance(FILE_TEXT);
-    let distance_table = construct_distance_table(&distances);
-    let cities = get_all_locations(&distances);
-    let min_distance = calculate_shortest_distance(&cities, &distance_table);
-    let max_distance = calculate_longest_distance(&cities, &distance_table);
-    println!(
-        "min_distance is {} and max_distance is {}",
-        min_distance, max_distance
-    );
+pub struct Day09;
+
+impl Solver for Day09 {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        let distances = parse_list_into_distance(input);
+        let distance_table = construct_distance_table(&distances);
+        let cities = get_all_locations(&distances);
+        Ok(calculate_shortest_distance(&cities, &distance_table).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        let distances = parse_list_into_distance(input);
+        let distance_table = construct_distance_table(&distances);
+        let cities = get_all_locations(&distances);
+        Ok(calculate_longest_distance(&cities, &distance_table).to_string())
+    }
 }
 
 #[cfg(test)]
@@ -150,22 +122,23 @@ mod tests {
 
     #[test]
     fn test_get_all_locations() {
-        let mut distances = Vec::new();
-        distances.push(Distance {
-            origin: String::from("London"),
-            destination: String::from("Dublin"),
-            distance: 464,
-        });
-        distances.push(Distance {
-            origin: String::from("London"),
-            destination: String::from("Belfast"),
-            distance: 518,
-        });
-        distances.push(Distance {
-            origin: String::from("Dublin"),
-            destination: String::from("Belfast"),
-            distance: 141,
-        });
+        let distances = vec![
+            Distance {
+                origin: String::from("London"),
+                destination: String::from("Dublin"),
+                weight: 464,
+            },
+            Distance {
+                origin: String::from("London"),
+                destination: String::from("Belfast"),
+                weight: 518,
+            },
+            Distance {
+                origin: String::from("Dublin"),
+                destination: String::from("Belfast"),
+                weight: 141,
+            },
+        ];
         let mut cities = HashSet::new();
         cities.insert(String::from("London"));
         cities.insert(String::from("Dublin"));
@@ -175,22 +148,23 @@ mod tests {
 
     #[test]
     fn test_construct_distance_table() {
-        let mut distances = Vec::new();
-        distances.push(Distance {
-            origin: String::from("London"),
-            destination: String::from("Dublin"),
-            distance: 464,
-        });
-        distances.push(Distance {
-            origin: String::from("London"),
-            destination: String::from("Belfast"),
-            distance: 518,
-        });
-        distances.push(Distance {
-            origin: String::from("Dublin"),
-            destination: String::from("Belfast"),
-            distance: 141,
-        });
+        let distances = vec![
+            Distance {
+                origin: String::from("London"),
+                destination: String::from("Dublin"),
+                weight: 464,
+            },
+            Distance {
+                origin: String::from("London"),
+                destination: String::from("Belfast"),
+                weight: 518,
+            },
+            Distance {
+                origin: String::from("Dublin"),
+                destination: String::from("Belfast"),
+                weight: 141,
+            },
+        ];
         let mut map = HashMap::new();
         map.insert((String::from("London"), String::from("Dublin")), 464);
         map.insert((String::from("Dublin"), String::from("London")), 464);
@@ -208,10 +182,11 @@ mod tests {
         let str_3 = "Dublin to Belfast = 141";
         let final_str = format!("{}\n{}\n{}", str_1, str_2, str_3);
 
-        let mut distances: Vec<Distance> = Vec::new();
-        distances.push(Distance::from(str_1));
-        distances.push(Distance::from(str_2));
-        distances.push(Distance::from(str_3));
+        let distances = vec![
+            Distance::from(str_1),
+            Distance::from(str_2),
+            Distance::from(str_3),
+        ];
         assert_eq!(parse_list_into_distance(&final_str), distances);
     }
 
@@ -226,7 +201,7 @@ mod tests {
             Distance {
                 origin: String::from("London"),
                 destination: String::from("Dublin"),
-                distance: 464
+                weight: 464
             }
         );
         assert_eq!(
@@ -234,7 +209,7 @@ mod tests {
             Distance {
                 origin: String::from("London"),
                 destination: String::from("Belfast"),
-                distance: 518
+                weight: 518
             }
         );
         assert_eq!(
@@ -242,7 +217,7 @@ mod tests {
             Distance {
                 origin: String::from("Dublin"),
                 destination: String::from("Belfast"),
-                distance: 141
+                weight: 141
             }
         );
     }
@@ -1,4 +1,6 @@
-fn parse_input(input: String) -> String {
+use aoc_common::{Answer, Solver};
+
+pub fn parse_input(input: String) -> String {
     let mut previous_char = 'x';
     let mut previous_count: u8 = 1;
     let mut output: String = "".to_string();
@@ -27,15 +29,28 @@ fn parse_input(input: String) -> String {
     output.push(previous_char);
     output
 }
-fn main() {
-    let mut input = "1113222113".to_string();
+pub fn look_and_say(input: &str, rounds: usize) -> String {
+    let mut output = input.to_string();
+    for _ in 0..rounds {
+        output = parse_input(output);
+    }
+    output
+}
+
+pub struct Day10;
 
-    for _ in 0..50 {
-        //println!("current output is {}", input);
-        input = parse_input(input);
+impl Solver for Day10 {
+    fn day(&self) -> u8 {
+        10
     }
 
-    println!("length of result is {}", input.len());
+    fn part1(&self, input: &str) -> Answer {
+        Ok(look_and_say(input.trim(), 40).len().to_string())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        Ok(look_and_say(input.trim(), 50).len().to_string())
+    }
 }
 
 #[cfg(test)]
@@ -50,4 +65,10 @@ mod tests {
         assert_eq!(parse_input(String::from("1211")), String::from("111221"));
         assert_eq!(parse_input(String::from("111221")), String::from("312211"));
     }
+
+    #[test]
+    fn test_look_and_say() {
+        assert_eq!(look_and_say("1", 5), String::from("312211"));
+        assert_eq!(look_and_say("1", 0), String::from("1"));
+    }
 }
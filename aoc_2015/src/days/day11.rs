@@ -1,3 +1,4 @@
+use aoc_common::{Answer, AocError, Budget, Solver};
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use std::collections::HashMap;
@@ -34,7 +35,7 @@ lazy_static! {
 }
 const BASE: u8 = 26;
 
-fn increment(input: &str) -> String {
+pub fn increment(input: &str) -> String {
     let mut values: Vec<u8> = Vec::new();
     let mut reversed_updated_values: Vec<u8> = Vec::new();
     let mut output: String = "".to_string();
@@ -76,7 +77,7 @@ fn increment(input: &str) -> String {
     output
 }
 
-fn check_increments(input: &str) -> bool {
+pub fn check_increments(input: &str) -> bool {
     for (char_1, char_2, char_3) in input.chars().tuple_windows::<(char, char, char)>() {
         if ALPHA_CHARACTERS[&char_1] + 1 == ALPHA_CHARACTERS[&char_2]
             && ALPHA_CHARACTERS[&char_2] + 1 == ALPHA_CHARACTERS[&char_3]
@@ -87,7 +88,7 @@ fn check_increments(input: &str) -> bool {
     false
 }
 
-fn check_forbidden_chars(input: &str) -> bool {
+pub fn check_forbidden_chars(input: &str) -> bool {
     for current_char in input.chars() {
         if current_char == 'i' || current_char == 'o' || current_char == 'l' {
             return false;
@@ -96,7 +97,7 @@ fn check_forbidden_chars(input: &str) -> bool {
     true
 }
 
-fn check_non_overlapping_repeated(input: &str) -> bool {
+pub fn check_non_overlapping_repeated(input: &str) -> bool {
     match input.len() {
         3.. => {
             let mut current_findings = 0;
@@ -123,15 +124,16 @@ fn check_non_overlapping_repeated(input: &str) -> bool {
     }
 }
 
-fn check_password(input: &str) -> bool {
+pub fn check_password(input: &str) -> bool {
     check_increments(input) && check_forbidden_chars(input) && check_non_overlapping_repeated(input)
 }
 
-fn find_next_password(input: &str) -> String {
+pub fn find_next_password(input: &str, budget: &Budget) -> Result<String, AocError> {
     let mut next_password = increment(input);
     loop {
+        budget.check()?;
         if check_password(&next_password) {
-            return next_password;
+            return Ok(next_password);
         } else {
             if !check_forbidden_chars(&next_password) {
                 //needs to skip the whole group with the forbidden characters
@@ -161,11 +163,29 @@ fn find_next_password(input: &str) -> String {
     }
 }
 
-fn main() {
-    let input = "vzbxkghb"; // first run
-    let input = "vzbxxyzz"; // second run
-    let new_password = find_next_password(input);
-    println!("The new password is {}", new_password);
+pub struct Day11;
+
+impl Solver for Day11 {
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        self.part1_within(input, &Budget::unlimited())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        self.part2_within(input, &Budget::unlimited())
+    }
+
+    fn part1_within(&self, input: &str, budget: &Budget) -> Answer {
+        find_next_password(input.trim(), budget)
+    }
+
+    // the password expires again, so this is the one after part 1
+    fn part2_within(&self, input: &str, budget: &Budget) -> Answer {
+        find_next_password(&find_next_password(input.trim(), budget)?, budget)
+    }
 }
 
 #[cfg(test)]
@@ -174,8 +194,20 @@ mod tests {
 
     #[test]
     fn test_find_next_password() {
-        assert_eq!(find_next_password("abcdefgh"), "abcdffaa");
-        assert_eq!(find_next_password("ghijklmn"), "ghjaabcc");
+        let budget = Budget::unlimited();
+        assert_eq!(
+            find_next_password("abcdefgh", &budget),
+            Ok(String::from("abcdffaa"))
+        );
+        assert_eq!(
+            find_next_password("ghijklmn", &budget),
+            Ok(String::from("ghjaabcc"))
+        );
+        budget.cancel();
+        assert!(matches!(
+            find_next_password("abcdefgh", &budget),
+            Err(AocError::Timeout(_))
+        ));
     }
 
     #[test]
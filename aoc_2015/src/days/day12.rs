@@ -0,0 +1,314 @@
+use aoc_common::{Answer, AocError, Solver};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub fn get_numbers_from_json(json: &str) -> Result<Vec<i64>, AocError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"(-?\d+)[,}\]]").unwrap();
+    }
+    RE.captures_iter(json)
+        .map(|capture| {
+            capture[1]
+                .parse()
+                .map_err(|_| AocError::Parse(format!("number out of range: {}", &capture[1])))
+        })
+        .collect()
+}
+
+pub fn sum_input(numbers: Vec<i64>) -> Result<i64, AocError> {
+    numbers
+        .into_iter()
+        .try_fold(0i64, |a, b| a.checked_add(b))
+        .ok_or_else(|| AocError::Parse(String::from("the sum overflows")))
+}
+
+// what a json value amounts to: strings are kept so objects can be checked
+// for the ignored value, everything else is reduced to its sum
+enum Value {
+    Sum(i64),
+    Text(String),
+}
+
+impl Value {
+    fn sum(&self) -> i64 {
+        match self {
+            Value::Sum(sum) => *sum,
+            Value::Text(_) => 0,
+        }
+    }
+}
+
+// Recursive descent over the json text. Objects with `ignore` as one of their
+// values (not keys) count as zero
+struct Walker<'a> {
+    json: &'a [u8],
+    position: usize,
+    ignore: &'a str,
+}
+
+impl Walker<'_> {
+    fn error(&self, what: &str) -> AocError {
+        AocError::Parse(format!("{} at byte {}", what, self.position))
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        while self
+            .json
+            .get(self.position)
+            .is_some_and(|x| x.is_ascii_whitespace())
+        {
+            self.position += 1;
+        }
+        self.json.get(self.position).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), AocError> {
+        match self.peek() {
+            Some(x) if x == byte => {
+                self.position += 1;
+                Ok(())
+            }
+            _ => Err(self.error(&format!("expected '{}'", byte as char))),
+        }
+    }
+
+    fn add(&self, total: i64, value: i64) -> Result<i64, AocError> {
+        total
+            .checked_add(value)
+            .ok_or_else(|| self.error("the sum overflows"))
+    }
+
+    fn value(&mut self) -> Result<Value, AocError> {
+        match self.peek() {
+            Some(b'{') => self.object(),
+            Some(b'[') => self.array(),
+            Some(b'"') => self.string().map(Value::Text),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(b't' | b'f' | b'n') => self.literal(),
+            _ => Err(self.error("expected a value")),
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, AocError> {
+        self.expect(b'{')?;
+        let (mut total, mut ignored) = (0, false);
+        if self.peek() == Some(b'}') {
+            self.position += 1;
+            return Ok(Value::Sum(0));
+        }
+        loop {
+            self.string()?;
+            self.expect(b':')?;
+            match self.value()? {
+                Value::Text(text) => ignored |= text == self.ignore,
+                Value::Sum(sum) => total = self.add(total, sum)?,
+            }
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b'}') => break,
+                _ => return Err(self.error("expected ',' or '}'")),
+            }
+        }
+        self.position += 1;
+        Ok(Value::Sum(if ignored { 0 } else { total }))
+    }
+
+    fn array(&mut self) -> Result<Value, AocError> {
+        self.expect(b'[')?;
+        let mut total = 0;
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            return Ok(Value::Sum(0));
+        }
+        loop {
+            let value = self.value()?;
+            total = self.add(total, value.sum())?;
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b']') => break,
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+        self.position += 1;
+        Ok(Value::Sum(total))
+    }
+
+    fn string(&mut self) -> Result<String, AocError> {
+        self.expect(b'"')?;
+        let mut text = Vec::new();
+        loop {
+            let byte = *self
+                .json
+                .get(self.position)
+                .ok_or_else(|| self.error("unterminated string"))?;
+            self.position += 1;
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let escaped = *self
+                        .json
+                        .get(self.position)
+                        .ok_or_else(|| self.error("unterminated string"))?;
+                    self.position += 1;
+                    match escaped {
+                        b'n' => text.push(b'\n'),
+                        b't' => text.push(b'\t'),
+                        b'r' => text.push(b'\r'),
+                        b'b' => text.push(8),
+                        b'f' => text.push(12),
+                        b'u' => {
+                            let character = self
+                                .json
+                                .get(self.position..self.position + 4)
+                                .and_then(|x| std::str::from_utf8(x).ok())
+                                .and_then(|x| u32::from_str_radix(x, 16).ok())
+                                .map(|x| char::from_u32(x).unwrap_or(char::REPLACEMENT_CHARACTER))
+                                .ok_or_else(|| self.error("invalid \\u escape"))?;
+                            self.position += 4;
+                            text.extend(character.to_string().bytes());
+                        }
+                        // \" \\ and \/ stand for themselves
+                        x => text.push(x),
+                    }
+                }
+                x => text.push(x),
+            }
+        }
+        String::from_utf8(text).map_err(|_| self.error("invalid utf-8 in string"))
+    }
+
+    fn number(&mut self) -> Result<Value, AocError> {
+        let start = self.position;
+        self.position += 1;
+        while self
+            .json
+            .get(self.position)
+            .is_some_and(|x| x.is_ascii_digit())
+        {
+            self.position += 1;
+        }
+        let number = std::str::from_utf8(&self.json[start..self.position])
+            .ok()
+            .and_then(|x| x.parse().ok())
+            .ok_or_else(|| self.error("invalid number"))?;
+        Ok(Value::Sum(number))
+    }
+
+    fn literal(&mut self) -> Result<Value, AocError> {
+        for literal in ["true", "false", "null"] {
+            if self.json[self.position..].starts_with(literal.as_bytes()) {
+                self.position += literal.len();
+                return Ok(Value::Sum(0));
+            }
+        }
+        Err(self.error("expected a value"))
+    }
+}
+
+pub fn sum_json_ignoring(json: &str, ignore: &str) -> Result<i64, AocError> {
+    let mut walker = Walker {
+        json: json.as_bytes(),
+        position: 0,
+        ignore,
+    };
+    if walker.peek().is_none() {
+        return Ok(0);
+    }
+    let sum = walker.value()?.sum();
+    match walker.peek() {
+        None => Ok(sum),
+        Some(_) => Err(walker.error("trailing data")),
+    }
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        sum_input(get_numbers_from_json(input)?).map(|x| x.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        sum_json_ignoring(input, "red").map(|x| x.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sum_input() {
+        assert_eq!(sum_input(vec![1, 2, 3]), Ok(6));
+        assert_eq!(sum_input(vec![2, 4]), Ok(6));
+        assert_eq!(sum_input(vec![]), Ok(0));
+        assert!(sum_input(vec![i64::MAX, 1]).is_err());
+    }
+
+    #[test]
+    fn test_get_numbers_from_json() {
+        assert_eq!(get_numbers_from_json("[1,2,3]"), Ok(vec![1, 2, 3]));
+        assert_eq!(get_numbers_from_json(r#"{"a":2,"b":4}"#), Ok(vec![2, 4]));
+        assert_eq!(get_numbers_from_json("[[[3]]]"), Ok(vec![3]));
+        assert_eq!(
+            get_numbers_from_json(r#"{"a":{"b":4},"c":-1}"#),
+            Ok(vec![4, -1])
+        );
+        assert_eq!(get_numbers_from_json(r#"{"a":[-1,1]}"#), Ok(vec![-1, 1]));
+        assert_eq!(get_numbers_from_json(r#"[-1,{"a":1}]"#), Ok(vec![-1, 1]));
+        assert_eq!(get_numbers_from_json("[]"), Ok(vec![]));
+        assert_eq!(get_numbers_from_json("{}"), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_sum_json_ignoring() {
+        assert_eq!(sum_json_ignoring("[1,2,3]", "red"), Ok(6));
+        assert_eq!(
+            sum_json_ignoring(r#"[1,{"c":"red","b":2},3]"#, "red"),
+            Ok(4)
+        );
+        assert_eq!(
+            sum_json_ignoring(r#"{"d":"red","e":[1,2,3,4],"f":5}"#, "red"),
+            Ok(0)
+        );
+        assert_eq!(sum_json_ignoring(r#"[1,"red",5]"#, "red"), Ok(6));
+        assert_eq!(sum_json_ignoring(r#"{"a":{"b":4},"c":-1}"#, "red"), Ok(3));
+        assert_eq!(
+            sum_json_ignoring(" { \"a\" : [ 1 , true, null ] } ", "red"),
+            Ok(1)
+        );
+        assert_eq!(sum_json_ignoring("", "red"), Ok(0));
+    }
+
+    #[test]
+    fn test_sum_json_ignoring_keys_and_escapes() {
+        // only values are checked against the ignored string, never keys
+        assert_eq!(sum_json_ignoring(r#"{"red":1}"#, "red"), Ok(1));
+        assert_eq!(sum_json_ignoring(r#"["a\"b",1]"#, "red"), Ok(1));
+        assert_eq!(sum_json_ignoring(r#"{"a":"r\u0065d","b":1}"#, "red"), Ok(0));
+        assert_eq!(sum_json_ignoring(r#"{"a":"\"red\"","b":1}"#, "red"), Ok(1));
+    }
+
+    #[test]
+    fn test_sum_json_ignoring_malformed() {
+        for json in [
+            "[1,2",
+            "{\"a\" 1}",
+            "[1,,2]",
+            "\"abc",
+            "[1] 2",
+            "[-]",
+            "{1:2}",
+        ] {
+            assert!(
+                matches!(sum_json_ignoring(json, "red"), Err(AocError::Parse(_))),
+                "{}",
+                json
+            );
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use aoc_common::{Answer, Solver};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashSet;
+
+use crate::graph::{self, Direction, Edge, RouteShape, WeightTable};
+
+pub const NEUTRAL_GUEST: &str = "me";
+
+pub fn parse_happiness(line: &str) -> Edge<i32> {
+    lazy_static! {
+        static ref HAPPINESS_RE: Regex = Regex::new(
+            r"^([a-zA-Z]+) would (gain|lose) ([0-9]+) happiness units? by sitting next to ([a-zA-Z]+)\.$"
+        )
+        .unwrap();
+    }
+    if let Some(cap) = HAPPINESS_RE.captures(line.trim()) {
+        let units = cap[3].parse::<i32>().unwrap();
+        return Edge {
+            origin: cap[1].to_string(),
+            destination: cap[4].to_string(),
+            weight: if &cap[2] == "gain" { units } else { -units },
+        };
+    }
+    panic!("invalid happiness line: {}", line)
+}
+
+pub fn parse_list_into_happiness(happiness_str: &str) -> Vec<Edge<i32>> {
+    happiness_str
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(parse_happiness)
+        .collect()
+}
+
+// the neutral guest gains and causes zero happiness next to everyone
+pub fn add_neutral_guest(happiness: &mut Vec<Edge<i32>>, name: &str) {
+    for guest in graph::get_all_nodes(happiness) {
+        happiness.push(Edge {
+            origin: String::from(name),
+            destination: guest.clone(),
+            weight: 0,
+        });
+        happiness.push(Edge {
+            origin: guest,
+            destination: String::from(name),
+            weight: 0,
+        });
+    }
+}
+
+// happiness is directed, but a seat next to someone counts both ways
+pub fn construct_seating_table(happiness: &[Edge<i32>]) -> WeightTable<i32> {
+    graph::symmetrize(&graph::construct_weight_table(
+        happiness,
+        Direction::Directed,
+    ))
+}
+
+pub fn calculate_optimal_happiness(guests: &HashSet<String>, table: &WeightTable<i32>) -> i32 {
+    graph::route_scores(guests, table, RouteShape::Cycle)
+        .max()
+        .unwrap_or(0)
+}
+
+pub fn solve(happiness: &[Edge<i32>]) -> i32 {
+    let table = construct_seating_table(happiness);
+    let guests = graph::get_all_nodes(happiness);
+    calculate_optimal_happiness(&guests, &table)
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        Ok(solve(&parse_list_into_happiness(input)).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        let mut happiness = parse_list_into_happiness(input);
+        add_neutral_guest(&mut happiness, NEUTRAL_GUEST);
+        Ok(solve(&happiness).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Alice would gain 54 happiness units by sitting next to Bob.
+                           Alice would lose 79 happiness units by sitting next to Carol.
+                           Alice would lose 2 happiness units by sitting next to David.
+                           Bob would gain 83 happiness units by sitting next to Alice.
+                           Bob would lose 7 happiness units by sitting next to Carol.
+                           Bob would lose 63 happiness units by sitting next to David.
+                           Carol would lose 62 happiness units by sitting next to Alice.
+                           Carol would gain 60 happiness units by sitting next to Bob.
+                           Carol would gain 55 happiness units by sitting next to David.
+                           David would gain 46 happiness units by sitting next to Alice.
+                           David would lose 7 happiness units by sitting next to Bob.
+                           David would gain 41 happiness units by sitting next to Carol.";
+
+    #[test]
+    fn test_parse_happiness() {
+        assert_eq!(
+            parse_happiness("Alice would gain 54 happiness units by sitting next to Bob."),
+            Edge {
+                origin: String::from("Alice"),
+                destination: String::from("Bob"),
+                weight: 54
+            }
+        );
+        assert_eq!(
+            parse_happiness("Alice would lose 79 happiness units by sitting next to Carol."),
+            Edge {
+                origin: String::from("Alice"),
+                destination: String::from("Carol"),
+                weight: -79
+            }
+        );
+    }
+
+    #[test]
+    fn test_construct_seating_table() {
+        let table = construct_seating_table(&parse_list_into_happiness(EXAMPLE));
+        assert_eq!(table[&(String::from("Alice"), String::from("Bob"))], 137);
+        assert_eq!(table[&(String::from("Bob"), String::from("Alice"))], 137);
+        assert_eq!(table[&(String::from("Carol"), String::from("David"))], 96);
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(solve(&parse_list_into_happiness(EXAMPLE)), 330);
+    }
+
+    #[test]
+    fn test_add_neutral_guest() {
+        let mut happiness = parse_list_into_happiness(EXAMPLE);
+        add_neutral_guest(&mut happiness, NEUTRAL_GUEST);
+        assert_eq!(happiness.len(), 20);
+        assert_eq!(graph::get_all_nodes(&happiness).len(), 5);
+        // the weakest link (David and Alice, 44) is replaced by the neutral seat
+        assert_eq!(solve(&happiness), 286);
+    }
+}
@@ -0,0 +1,269 @@
+use aoc_common::{Answer, AocError, Solver};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::str::FromStr;
+
+pub const RACE_DURATION: u32 = 2503;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Reindeer {
+    pub name: String,
+    pub speed: u32,
+    pub fly_time: u32,
+    pub rest_time: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Standing {
+    pub distance: u32,
+    pub points: u32,
+}
+
+impl FromStr for Reindeer {
+    type Err = AocError;
+
+    fn from_str(data: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REINDEER_RE: Regex = Regex::new(
+                r"^([a-zA-Z]+) can fly ([0-9]+) km/s for ([0-9]+) seconds?, but then must rest for ([0-9]+) seconds?\.$"
+            )
+            .unwrap();
+        }
+        let invalid = || AocError::Parse(format!("invalid reindeer line: {}", data));
+        let cap = REINDEER_RE.captures(data.trim()).ok_or_else(invalid)?;
+        let reindeer = Reindeer {
+            name: cap[1].to_string(),
+            speed: cap[2].parse().map_err(|_| invalid())?,
+            fly_time: cap[3].parse().map_err(|_| invalid())?,
+            rest_time: cap[4].parse().map_err(|_| invalid())?,
+        };
+        // a zero length fly and rest cycle has no pattern to repeat
+        if reindeer.cycle() == 0 {
+            return Err(AocError::Parse(format!(
+                "reindeer never flies nor rests: {}",
+                data
+            )));
+        }
+        Ok(reindeer)
+    }
+}
+
+impl Reindeer {
+    // in u64 so that two u32 times near the limit can't overflow
+    fn cycle(&self) -> u64 {
+        self.fly_time as u64 + self.rest_time as u64
+    }
+
+    // `second` is zero based: 0 is the first second of the race
+    fn is_flying(&self, second: u32) -> bool {
+        (second as u64) % self.cycle() < self.fly_time as u64
+    }
+}
+
+pub fn parse_list_into_reindeers(reindeers_str: &str) -> Result<Vec<Reindeer>, AocError> {
+    reindeers_str
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+// Runs the race one second at a time. `on_second` sees the standings at the
+// end of every second (1 based), which is what the trace mode prints.
+pub fn race<F>(reindeers: &[Reindeer], duration: u32, mut on_second: F) -> Vec<Standing>
+where
+    F: FnMut(u32, &[Standing]),
+{
+    let mut standings = vec![Standing::default(); reindeers.len()];
+    for second in 0..duration {
+        for (reindeer, standing) in reindeers.iter().zip(standings.iter_mut()) {
+            if reindeer.is_flying(second) {
+                standing.distance = standing.distance.saturating_add(reindeer.speed);
+            }
+        }
+        let lead = standings.iter().map(|x| x.distance).max().unwrap_or(0);
+        for standing in standings.iter_mut() {
+            if standing.distance == lead {
+                standing.points += 1;
+            }
+        }
+        on_second(second + 1, &standings);
+    }
+    standings
+}
+
+pub fn format_leaderboard(second: u32, reindeers: &[Reindeer], standings: &[Standing]) -> String {
+    let mut order: Vec<usize> = (0..reindeers.len()).collect();
+    order.sort_by(|a, b| standings[*b].distance.cmp(&standings[*a].distance));
+    let entries: Vec<String> = order
+        .iter()
+        .map(|x| {
+            format!(
+                "{} {}km {}pts",
+                reindeers[*x].name, standings[*x].distance, standings[*x].points
+            )
+        })
+        .collect();
+    format!("{:>5}s: {}", second, entries.join(", "))
+}
+
+// returns the (name, value) of the leader for the given metric
+pub fn find_winner<'a, F>(
+    reindeers: &'a [Reindeer],
+    standings: &[Standing],
+    metric: F,
+) -> Option<(&'a str, u32)>
+where
+    F: Fn(&Standing) -> u32,
+{
+    reindeers
+        .iter()
+        .zip(standings)
+        .map(|(reindeer, standing)| (reindeer.name.as_str(), metric(standing)))
+        .max_by_key(|(_, value)| *value)
+}
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        let reindeers = parse_list_into_reindeers(input)?;
+        let standings = race(&reindeers, RACE_DURATION, |_, _| {});
+        match find_winner(&reindeers, &standings, |x| x.distance) {
+            Some((_, distance)) => Ok(distance.to_string()),
+            None => Err(AocError::NoSolution(String::from(
+                "no reindeer in the race",
+            ))),
+        }
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        let reindeers = parse_list_into_reindeers(input)?;
+        let standings = race(&reindeers, RACE_DURATION, |_, _| {});
+        match find_winner(&reindeers, &standings, |x| x.points) {
+            Some((_, points)) => Ok(points.to_string()),
+            None => Err(AocError::NoSolution(String::from(
+                "no reindeer in the race",
+            ))),
+        }
+    }
+
+    // usage: 14 [seconds] [trace]
+    fn run_mode(&self, input: &str, args: &[String]) -> Answer {
+        let duration = args
+            .iter()
+            .find_map(|x| x.parse::<u32>().ok())
+            .unwrap_or(RACE_DURATION);
+        let trace = args.iter().any(|x| x == "trace");
+
+        let reindeers = parse_list_into_reindeers(input)?;
+        let mut output = Vec::new();
+        let standings = race(&reindeers, duration, |second, standings| {
+            if trace {
+                output.push(format_leaderboard(second, &reindeers, standings));
+            }
+        });
+        if let Some((name, distance)) = find_winner(&reindeers, &standings, |x| x.distance) {
+            output.push(format!("{} wins by distance with {}km", name, distance));
+        }
+        if let Some((name, points)) = find_winner(&reindeers, &standings, |x| x.points) {
+            output.push(format!("{} wins by points with {} points", name, points));
+        }
+        Ok(output.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+                           Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.";
+
+    #[test]
+    fn test_reindeer_from_string() {
+        assert_eq!(
+            "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds."
+                .parse::<Reindeer>(),
+            Ok(Reindeer {
+                name: String::from("Comet"),
+                speed: 14,
+                fly_time: 10,
+                rest_time: 127
+            })
+        );
+        assert!(matches!(
+            "Comet can fly fast.".parse::<Reindeer>(),
+            Err(AocError::Parse(_))
+        ));
+        assert!(matches!(
+            "Comet can fly 99999999999 km/s for 1 second, but then must rest for 1 second."
+                .parse::<Reindeer>(),
+            Err(AocError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_reindeer_without_cycle() {
+        assert!(matches!(
+            "Dasher can fly 3 km/s for 0 seconds, but then must rest for 0 seconds."
+                .parse::<Reindeer>(),
+            Err(AocError::Parse(x)) if x.contains("never flies nor rests")
+        ));
+    }
+
+    #[test]
+    fn test_is_flying_long_cycle() {
+        let dasher: Reindeer =
+            "Dasher can fly 3 km/s for 4000000000 seconds, but then must rest for 4000000000 seconds."
+                .parse()
+                .unwrap();
+        assert!(dasher.is_flying(0));
+        assert!(dasher.is_flying(3999999999));
+        assert!(!dasher.is_flying(4000000000));
+    }
+
+    #[test]
+    fn test_is_flying() {
+        let comet = &parse_list_into_reindeers(EXAMPLE).unwrap()[0];
+        assert!(comet.is_flying(0));
+        assert!(comet.is_flying(9));
+        assert!(!comet.is_flying(10));
+        assert!(!comet.is_flying(136));
+        assert!(comet.is_flying(137));
+    }
+
+    #[test]
+    fn test_race() {
+        let reindeers = parse_list_into_reindeers(EXAMPLE).unwrap();
+        let standings = race(&reindeers, 1000, |_, _| {});
+        assert_eq!(standings[0].distance, 1120);
+        assert_eq!(standings[1].distance, 1056);
+        assert_eq!(standings[0].points, 312);
+        assert_eq!(standings[1].points, 689);
+        assert_eq!(
+            find_winner(&reindeers, &standings, |x| x.distance),
+            Some(("Comet", 1120))
+        );
+        assert_eq!(
+            find_winner(&reindeers, &standings, |x| x.points),
+            Some(("Dancer", 689))
+        );
+    }
+
+    #[test]
+    fn test_race_trace() {
+        let reindeers = parse_list_into_reindeers(EXAMPLE).unwrap();
+        let mut trace = Vec::new();
+        race(&reindeers, 140, |second, standings| {
+            trace.push(format_leaderboard(second, &reindeers, standings));
+        });
+        assert_eq!(trace.len(), 140);
+        assert_eq!(trace[0], "    1s: Dancer 16km 1pts, Comet 14km 0pts");
+        assert_eq!(trace[139], "  140s: Comet 182km 1pts, Dancer 176km 139pts");
+    }
+}
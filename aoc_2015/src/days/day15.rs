@@ -0,0 +1,193 @@
+use aoc_common::{Answer, Solver};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub const TEASPOONS: u32 = 100;
+pub const CALORIE_TARGET: i64 = 500;
+// capacity, durability, flavor and texture. Calories don't count for the score
+pub const SCORED_PROPERTIES: usize = 4;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Ingredient {
+    pub name: String,
+    pub properties: [i64; SCORED_PROPERTIES],
+    pub calories: i64,
+}
+
+impl From<&str> for Ingredient {
+    fn from(data: &str) -> Self {
+        lazy_static! {
+            static ref INGREDIENT_RE: Regex = Regex::new(
+                r"^([a-zA-Z]+): capacity (-?[0-9]+), durability (-?[0-9]+), flavor (-?[0-9]+), texture (-?[0-9]+), calories (-?[0-9]+)$"
+            )
+            .unwrap();
+        }
+        if let Some(cap) = INGREDIENT_RE.captures(data.trim()) {
+            return Ingredient {
+                name: cap[1].to_string(),
+                properties: [
+                    cap[2].parse().unwrap(),
+                    cap[3].parse().unwrap(),
+                    cap[4].parse().unwrap(),
+                    cap[5].parse().unwrap(),
+                ],
+                calories: cap[6].parse().unwrap(),
+            };
+        }
+        panic!("invalid ingredient line: {}", data)
+    }
+}
+
+pub fn parse_list_into_ingredients(ingredients_str: &str) -> Vec<Ingredient> {
+    ingredients_str
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(Ingredient::from)
+        .collect()
+}
+
+// Calls `visit` with every way of splitting `total` into `amounts.len()`
+// ordered parts. Works for any number of ingredients, instead of one nested
+// loop per ingredient.
+pub fn for_each_composition<F>(total: u32, amounts: &mut [u32], visit: &mut F)
+where
+    F: FnMut(&[u32]),
+{
+    fn fill<F>(remaining: u32, position: usize, amounts: &mut [u32], visit: &mut F)
+    where
+        F: FnMut(&[u32]),
+    {
+        if position + 1 == amounts.len() {
+            amounts[position] = remaining;
+            visit(amounts);
+            return;
+        }
+        for amount in 0..=remaining {
+            amounts[position] = amount;
+            fill(remaining - amount, position + 1, amounts, visit);
+        }
+    }
+
+    if !amounts.is_empty() {
+        fill(total, 0, amounts, visit);
+    }
+}
+
+pub fn calculate_score(ingredients: &[Ingredient], amounts: &[u32]) -> i64 {
+    let mut score = 1;
+    for property in 0..SCORED_PROPERTIES {
+        let total: i64 = ingredients
+            .iter()
+            .zip(amounts)
+            .map(|(ingredient, amount)| ingredient.properties[property] * *amount as i64)
+            .sum();
+        score *= total.max(0);
+    }
+    score
+}
+
+pub fn calculate_calories(ingredients: &[Ingredient], amounts: &[u32]) -> i64 {
+    ingredients
+        .iter()
+        .zip(amounts)
+        .map(|(ingredient, amount)| ingredient.calories * *amount as i64)
+        .sum()
+}
+
+pub fn find_best_score(
+    ingredients: &[Ingredient],
+    teaspoons: u32,
+    calorie_target: Option<i64>,
+) -> i64 {
+    let mut best_score = 0;
+    let mut amounts = vec![0; ingredients.len()];
+    for_each_composition(teaspoons, &mut amounts, &mut |amounts| {
+        if let Some(target) = calorie_target {
+            if calculate_calories(ingredients, amounts) != target {
+                return;
+            }
+        }
+        best_score = best_score.max(calculate_score(ingredients, amounts));
+    });
+    best_score
+}
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        let ingredients = parse_list_into_ingredients(input);
+        Ok(find_best_score(&ingredients, TEASPOONS, None).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        let ingredients = parse_list_into_ingredients(input);
+        Ok(find_best_score(&ingredients, TEASPOONS, Some(CALORIE_TARGET)).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str =
+        "Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+         Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3";
+
+    #[test]
+    fn test_ingredient_from_string() {
+        assert_eq!(
+            Ingredient::from(
+                "Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3"
+            ),
+            Ingredient {
+                name: String::from("Cinnamon"),
+                properties: [2, 3, -2, -1],
+                calories: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_for_each_composition() {
+        let mut compositions = Vec::new();
+        for_each_composition(2, &mut [0; 3], &mut |amounts| {
+            compositions.push(amounts.to_vec())
+        });
+        assert_eq!(
+            compositions,
+            vec![
+                vec![0, 0, 2],
+                vec![0, 1, 1],
+                vec![0, 2, 0],
+                vec![1, 0, 1],
+                vec![1, 1, 0],
+                vec![2, 0, 0]
+            ]
+        );
+
+        let mut count = 0;
+        for_each_composition(100, &mut [0; 4], &mut |_| count += 1);
+        assert_eq!(count, 176851);
+        for_each_composition(100, &mut [], &mut |_| panic!());
+    }
+
+    #[test]
+    fn test_calculate_score() {
+        let ingredients = parse_list_into_ingredients(EXAMPLE);
+        assert_eq!(calculate_score(&ingredients, &[44, 56]), 62842880);
+        assert_eq!(calculate_score(&ingredients, &[100, 0]), 0);
+        assert_eq!(calculate_calories(&ingredients, &[40, 60]), 500);
+    }
+
+    #[test]
+    fn test_find_best_score() {
+        let ingredients = parse_list_into_ingredients(EXAMPLE);
+        assert_eq!(find_best_score(&ingredients, 100, None), 62842880);
+        assert_eq!(find_best_score(&ingredients, 100, Some(500)), 57600000);
+    }
+}
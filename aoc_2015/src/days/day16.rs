@@ -0,0 +1,230 @@
+use aoc_common::{Answer, AocError, Solver};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+
+// What the MFCSAM reads off the gift. Part 1 takes every reading literally.
+pub const TICKER_TAPE: &str = "children: 3
+cats: 7
+samoyeds: 2
+pomeranians: 3
+akitas: 0
+vizslas: 0
+goldfish: 5
+trees: 3
+cars: 2
+perfumes: 1";
+
+// Part 2: the retroencabulator reports ranges for some compounds
+pub const RANGED_TICKER_TAPE: &str = "children: 3
+cats > 7
+samoyeds: 2
+pomeranians < 3
+akitas: 0
+vizslas: 0
+goldfish < 5
+trees > 3
+cars: 2
+perfumes: 1";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Comparison {
+    Equal,
+    GreaterThan,
+    LessThan,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Criterion {
+    pub compound: String,
+    pub comparison: Comparison,
+    pub value: u32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Sue {
+    pub number: u32,
+    // only the compounds we remember, anything else is unknown
+    pub compounds: HashMap<String, u32>,
+}
+
+impl Comparison {
+    fn check(&self, remembered: u32, reading: u32) -> bool {
+        match *self {
+            Comparison::Equal => remembered == reading,
+            Comparison::GreaterThan => remembered > reading,
+            Comparison::LessThan => remembered < reading,
+        }
+    }
+}
+
+impl From<&str> for Criterion {
+    fn from(data: &str) -> Self {
+        lazy_static! {
+            static ref CRITERION_RE: Regex =
+                Regex::new(r"^([a-z]+)\s*(:|=|>|<)\s*([0-9]+)$").unwrap();
+        }
+        if let Some(cap) = CRITERION_RE.captures(data.trim()) {
+            return Criterion {
+                compound: cap[1].to_string(),
+                comparison: match &cap[2] {
+                    ">" => Comparison::GreaterThan,
+                    "<" => Comparison::LessThan,
+                    _ => Comparison::Equal,
+                },
+                value: cap[3].parse().unwrap(),
+            };
+        }
+        panic!("invalid criterion: {}", data)
+    }
+}
+
+impl From<&str> for Sue {
+    fn from(data: &str) -> Self {
+        lazy_static! {
+            static ref SUE_RE: Regex = Regex::new(r"^Sue ([0-9]+): (.*)$").unwrap();
+            static ref COMPOUND_RE: Regex = Regex::new(r"([a-z]+): ([0-9]+)").unwrap();
+        }
+        if let Some(cap) = SUE_RE.captures(data.trim()) {
+            let mut compounds = HashMap::new();
+            for compound in COMPOUND_RE.captures_iter(&cap[2]) {
+                compounds.insert(compound[1].to_string(), compound[2].parse().unwrap());
+            }
+            return Sue {
+                number: cap[1].parse().unwrap(),
+                compounds,
+            };
+        }
+        panic!("invalid sue: {}", data)
+    }
+}
+
+impl Sue {
+    fn matches(&self, criteria: &[Criterion]) -> bool {
+        criteria
+            .iter()
+            .all(|criterion| match self.compounds.get(&criterion.compound) {
+                Some(remembered) => criterion.comparison.check(*remembered, criterion.value),
+                None => true,
+            })
+    }
+}
+
+pub fn parse_criteria(criteria_str: &str) -> Vec<Criterion> {
+    criteria_str
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(Criterion::from)
+        .collect()
+}
+
+pub fn parse_list_into_sues(sues_str: &str) -> Vec<Sue> {
+    sues_str
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(Sue::from)
+        .collect()
+}
+
+pub fn find_matching_sues(sues: &[Sue], criteria: &[Criterion]) -> Vec<u32> {
+    sues.iter()
+        .filter(|sue| sue.matches(criteria))
+        .map(|sue| sue.number)
+        .collect()
+}
+
+pub fn find_single_sue(input: &str, ticker_tape: &str) -> Answer {
+    let sues = parse_list_into_sues(input);
+    match find_matching_sues(&sues, &parse_criteria(ticker_tape))[..] {
+        [number] => Ok(number.to_string()),
+        ref matches => Err(AocError::NoSolution(format!(
+            "expected a single matching sue, found {:?}",
+            matches
+        ))),
+    }
+}
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        find_single_sue(input, TICKER_TAPE)
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        find_single_sue(input, RANGED_TICKER_TAPE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_criterion_from_string() {
+        assert_eq!(
+            Criterion::from("children: 3"),
+            Criterion {
+                compound: String::from("children"),
+                comparison: Comparison::Equal,
+                value: 3
+            }
+        );
+        assert_eq!(
+            Criterion::from("cats > 7").comparison,
+            Comparison::GreaterThan
+        );
+        assert_eq!(
+            Criterion::from("goldfish < 5").comparison,
+            Comparison::LessThan
+        );
+        assert_eq!(Criterion::from("cars = 2").comparison, Comparison::Equal);
+    }
+
+    #[test]
+    fn test_sue_from_string() {
+        let sue = Sue::from("Sue 12: goldfish: 6, trees: 9, akitas: 0");
+        assert_eq!(sue.number, 12);
+        assert_eq!(sue.compounds.len(), 3);
+        assert_eq!(sue.compounds["trees"], 9);
+        assert_eq!(sue.compounds.get("cats"), None);
+    }
+
+    #[test]
+    fn test_sue_matches() {
+        let exact = parse_criteria(TICKER_TAPE);
+        let ranged = parse_criteria(RANGED_TICKER_TAPE);
+        let sue = Sue::from("Sue 1: children: 3, cars: 2, perfumes: 1");
+        assert!(sue.matches(&exact));
+        assert!(sue.matches(&ranged));
+
+        let sue = Sue::from("Sue 2: cats: 7, trees: 3");
+        assert!(sue.matches(&exact));
+        assert!(!sue.matches(&ranged));
+
+        let sue = Sue::from("Sue 3: cats: 8, pomeranians: 1, goldfish: 2");
+        assert!(!sue.matches(&exact));
+        assert!(sue.matches(&ranged));
+    }
+
+    #[test]
+    fn test_find_matching_sues() {
+        let sues = parse_list_into_sues(
+            "Sue 1: children: 1, cars: 8, vizslas: 7
+             Sue 2: akitas: 0, perfumes: 1, cats: 7
+             Sue 3: trees: 10, goldfish: 0, samoyeds: 2",
+        );
+        assert_eq!(
+            find_matching_sues(&sues, &parse_criteria(TICKER_TAPE)),
+            vec![2]
+        );
+        assert_eq!(
+            find_matching_sues(&sues, &parse_criteria(RANGED_TICKER_TAPE)),
+            vec![3]
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use aoc_common::parse::parse_numbers;
+use aoc_common::{Answer, AocError, Solver};
+
+pub const TARGET_VOLUME: usize = 150;
+
+pub fn parse_containers(containers_str: &str) -> Result<Vec<usize>, AocError> {
+    parse_numbers(containers_str)
+}
+
+// combinations[k][v] is the number of subsets of k containers holding exactly
+// v liters. Each container is added once, walking k and v downwards so it is
+// never reused: O(n^2 * target) instead of O(2^n).
+pub fn count_combinations_by_size(containers: &[usize], target: usize) -> Vec<u64> {
+    let mut combinations = vec![vec![0u64; target + 1]; containers.len() + 1];
+    combinations[0][0] = 1;
+    for (added, container) in containers.iter().enumerate() {
+        for count in (0..=added).rev() {
+            for volume in (*container..=target).rev() {
+                combinations[count + 1][volume] += combinations[count][volume - container];
+            }
+        }
+    }
+    combinations.iter().map(|x| x[target]).collect()
+}
+
+pub fn count_combinations(containers: &[usize], target: usize) -> u64 {
+    count_combinations_by_size(containers, target).iter().sum()
+}
+
+// returns (minimum container count, combinations using that many containers)
+pub fn count_minimum_combinations(containers: &[usize], target: usize) -> Option<(usize, u64)> {
+    count_combinations_by_size(containers, target)
+        .into_iter()
+        .enumerate()
+        .find(|(_, combinations)| *combinations > 0)
+}
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        let containers = parse_containers(input)?;
+        Ok(count_combinations(&containers, TARGET_VOLUME).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        let containers = parse_containers(input)?;
+        match count_minimum_combinations(&containers, TARGET_VOLUME) {
+            Some((_, combinations)) => Ok(combinations.to_string()),
+            None => Err(AocError::NoSolution(format!(
+                "no combination holds {} liters",
+                TARGET_VOLUME
+            ))),
+        }
+    }
+
+    // usage: 17 [target]
+    fn run_mode(&self, input: &str, args: &[String]) -> Answer {
+        let target = match args.first() {
+            Some(arg) => arg
+                .parse()
+                .map_err(|_| AocError::Usage(String::from("17 [target volume]")))?,
+            None => TARGET_VOLUME,
+        };
+        let containers = parse_containers(input)?;
+        let mut output = vec![format!(
+            "{} combinations hold {} liters",
+            count_combinations(&containers, target),
+            target
+        )];
+        output.push(match count_minimum_combinations(&containers, target) {
+            Some((count, combinations)) => format!(
+                "{} combinations use the minimum of {} containers",
+                combinations, count
+            ),
+            None => format!("no combination holds {} liters", target),
+        });
+        Ok(output.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_containers() {
+        assert_eq!(
+            parse_containers("20\n15\n10\n5\n5\n"),
+            Ok(vec![20, 15, 10, 5, 5])
+        );
+        assert!(parse_containers("20 x").is_err());
+    }
+
+    #[test]
+    fn test_count_combinations() {
+        assert_eq!(count_combinations(&[20, 15, 10, 5, 5], 25), 4);
+        assert_eq!(count_combinations(&[20, 15, 10, 5, 5], 0), 1);
+        assert_eq!(count_combinations(&[20, 15, 10, 5, 5], 100), 0);
+        assert_eq!(count_combinations(&[], 25), 0);
+    }
+
+    #[test]
+    fn test_count_minimum_combinations() {
+        assert_eq!(
+            count_minimum_combinations(&[20, 15, 10, 5, 5], 25),
+            Some((2, 3))
+        );
+        assert_eq!(count_minimum_combinations(&[20, 15, 10, 5, 5], 100), None);
+    }
+
+    #[test]
+    fn test_count_combinations_scales() {
+        // 2^40 subsets would be far too many to enumerate
+        let containers = vec![1; 40];
+        assert_eq!(count_combinations(&containers, 20), 137846528820);
+        assert_eq!(
+            count_minimum_combinations(&containers, 20),
+            Some((20, 137846528820))
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use aoc_common::grid::Grid;
+use aoc_common::{Answer, Solver};
+
+pub const STEPS: usize = 100;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Mode {
+    Standard,
+    // the four corner lights are stuck on and ignore the rules
+    StuckCorners,
+}
+
+pub fn parse_grid(grid_str: &str) -> Grid<bool> {
+    let rows = grid_str
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.chars()
+                .map(|x| match x {
+                    '#' => true,
+                    '.' => false,
+                    _ => panic!("invalid light {:?}", x),
+                })
+                .collect()
+        })
+        .collect();
+    Grid::from_rows(rows)
+}
+
+pub fn render(grid: &Grid<bool>) -> String {
+    let mut output = String::new();
+    for y in 0..grid.height() {
+        for x in 0..grid.width() {
+            output.push(if grid.get(x, y) { '#' } else { '.' });
+        }
+        output.push('\n');
+    }
+    output
+}
+
+pub fn count_lights_on(grid: &Grid<bool>) -> usize {
+    grid.cells().filter(|x| **x).count()
+}
+
+pub fn turn_corners_on(grid: &mut Grid<bool>) {
+    if grid.width() == 0 || grid.height() == 0 {
+        return;
+    }
+    let (last_x, last_y) = (grid.width() - 1, grid.height() - 1);
+    for (x, y) in [(0, 0), (last_x, 0), (0, last_y), (last_x, last_y)] {
+        grid.set(x, y, true);
+    }
+}
+
+pub fn game_of_life(light: bool, neighbours: &[bool]) -> bool {
+    let neighbours_on = neighbours.iter().filter(|x| **x).count();
+    matches!((light, neighbours_on), (true, 2) | (true, 3) | (false, 3))
+}
+
+// `on_frame` receives the starting grid and every generation after it
+pub fn animate<F>(grid: &Grid<bool>, steps: usize, mode: Mode, mut on_frame: F) -> Grid<bool>
+where
+    F: FnMut(usize, &Grid<bool>),
+{
+    let mut current = grid.clone();
+    if mode == Mode::StuckCorners {
+        turn_corners_on(&mut current);
+    }
+    on_frame(0, &current);
+    for step in 1..=steps {
+        current = current.step(game_of_life);
+        if mode == Mode::StuckCorners {
+            turn_corners_on(&mut current);
+        }
+        on_frame(step, &current);
+    }
+    current
+}
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        let last = animate(&parse_grid(input), STEPS, Mode::Standard, |_, _| {});
+        Ok(count_lights_on(&last).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        let last = animate(&parse_grid(input), STEPS, Mode::StuckCorners, |_, _| {});
+        Ok(count_lights_on(&last).to_string())
+    }
+
+    // `dump` prints every frame, useful to eyeball small inputs
+    fn run_mode(&self, input: &str, args: &[String]) -> Answer {
+        let dump = args.iter().any(|x| x == "dump");
+        let grid = parse_grid(input);
+        let mut output = Vec::new();
+        for mode in [Mode::Standard, Mode::StuckCorners] {
+            let last = animate(&grid, STEPS, mode, |step, frame| {
+                if dump {
+                    output.push(format!("{:?} step {}:\n{}", mode, step, render(frame)));
+                }
+            });
+            output.push(format!(
+                "{:?}: {} lights on after {} steps",
+                mode,
+                count_lights_on(&last),
+                STEPS
+            ));
+        }
+        Ok(output.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = ".#.#.#
+                           ...##.
+                           #....#
+                           ..#...
+                           #.#..#
+                           ####..";
+
+    #[test]
+    fn test_parse_and_render() {
+        let grid = parse_grid(EXAMPLE);
+        assert_eq!(grid.width(), 6);
+        assert_eq!(grid.height(), 6);
+        assert!(grid.get(1, 0));
+        assert!(!grid.get(0, 0));
+        assert_eq!(count_lights_on(&grid), 15);
+        assert_eq!(
+            render(&grid),
+            ".#.#.#\n...##.\n#....#\n..#...\n#.#..#\n####..\n"
+        );
+    }
+
+    #[test]
+    fn test_game_of_life() {
+        assert!(game_of_life(true, &[true, true, false]));
+        assert!(game_of_life(true, &[true, true, true]));
+        assert!(!game_of_life(true, &[true]));
+        assert!(!game_of_life(true, &[true; 4]));
+        assert!(game_of_life(false, &[true, true, true, false]));
+        assert!(!game_of_life(false, &[true, true]));
+    }
+
+    #[test]
+    fn test_animate() {
+        let grid = parse_grid(EXAMPLE);
+        let last = animate(&grid, 4, Mode::Standard, |_, _| {});
+        assert_eq!(count_lights_on(&last), 4);
+        assert_eq!(
+            render(&last),
+            "......\n......\n..##..\n..##..\n......\n......\n"
+        );
+    }
+
+    #[test]
+    fn test_animate_stuck_corners() {
+        let grid = parse_grid(EXAMPLE);
+        let mut frames = Vec::new();
+        let last = animate(&grid, 5, Mode::StuckCorners, |_, frame| {
+            frames.push(render(frame))
+        });
+        assert_eq!(count_lights_on(&last), 17);
+        assert_eq!(frames.len(), 6);
+        assert_eq!(
+            frames[0],
+            "##.#.#\n...##.\n#....#\n..#...\n#.#..#\n####.#\n"
+        );
+        assert_eq!(
+            frames[5],
+            "##.###\n.##..#\n.##...\n.##...\n#.#...\n##...#\n"
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use aoc_common::{Answer, AocError, Budget, Solver};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+pub const START: &str = "e";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Replacement {
+    pub from: String,
+    pub to: Vec<String>,
+}
+
+// Splits a molecule into element symbols: an uppercase letter followed by an
+// optional lowercase one, plus the special `e` starting electron. Anything
+// else is rejected rather than dropped.
+pub fn tokenize(molecule: &str) -> Result<Vec<String>, AocError> {
+    lazy_static! {
+        static ref ELEMENT_RE: Regex = Regex::new(r"[A-Z][a-z]?|e").unwrap();
+    }
+    let mut elements = Vec::new();
+    let mut end = 0;
+    for element in ELEMENT_RE.find_iter(molecule) {
+        if element.start() != end {
+            break;
+        }
+        end = element.end();
+        elements.push(element.as_str().to_string());
+    }
+    match molecule[end..].chars().next() {
+        Some(x) => Err(AocError::Parse(format!(
+            "unexpected {:?} in molecule: {}",
+            x, molecule
+        ))),
+        None => Ok(elements),
+    }
+}
+
+impl FromStr for Replacement {
+    type Err = AocError;
+
+    fn from_str(data: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REPLACEMENT_RE: Regex =
+                Regex::new(r"^([A-Za-z]+)\s=>\s([A-Za-z]+)$").unwrap();
+        }
+        let cap = REPLACEMENT_RE
+            .captures(data.trim())
+            .ok_or_else(|| AocError::Parse(format!("invalid replacement: {}", data)))?;
+        Ok(Replacement {
+            from: cap[1].to_string(),
+            to: tokenize(&cap[2])?,
+        })
+    }
+}
+
+// rules first, then a blank line and the medicine molecule
+pub fn parse_input(input: &str) -> Result<(Vec<Replacement>, Vec<String>), AocError> {
+    let mut replacements = Vec::new();
+    let mut molecule = Vec::new();
+    for line in input.lines().map(|x| x.trim()).filter(|x| !x.is_empty()) {
+        if line.contains("=>") {
+            replacements.push(line.parse()?);
+        } else {
+            molecule = tokenize(line)?;
+        }
+    }
+    Ok((replacements, molecule))
+}
+
+pub fn find_position(molecule: &[String], pattern: &[String]) -> Option<usize> {
+    if pattern.is_empty() || pattern.len() > molecule.len() {
+        return None;
+    }
+    molecule.windows(pattern.len()).position(|x| x == pattern)
+}
+
+pub fn calibrate(replacements: &[Replacement], molecule: &[String]) -> usize {
+    let mut molecules = HashSet::new();
+    for replacement in replacements {
+        for (position, element) in molecule.iter().enumerate() {
+            if *element == replacement.from {
+                let mut new_molecule = molecule[..position].concat();
+                new_molecule.push_str(&replacement.to.concat());
+                new_molecule.push_str(&molecule[position + 1..].concat());
+                molecules.insert(new_molecule);
+            }
+        }
+    }
+    molecules.len()
+}
+
+// One greedy pass: keep undoing the first replacement that fits until only
+// `e` is left. Replacements from `e` may only undo the whole molecule. Rules
+// like `A => B` don't shorten the molecule, so a step into a molecule seen
+// before is skipped; that keeps the pass finite on cyclic rules.
+pub fn reduce(
+    replacements: &[&Replacement],
+    molecule: &[String],
+    budget: &Budget,
+) -> Result<Option<usize>, AocError> {
+    let mut current = molecule.to_vec();
+    let mut seen = HashSet::from([current.clone()]);
+    let mut steps = 0;
+    while current != [START] {
+        budget.check()?;
+        let next = replacements
+            .iter()
+            .filter(|x| x.from != START || current == x.to)
+            .find_map(|replacement| {
+                let position = find_position(&current, &replacement.to)?;
+                let mut next = current.clone();
+                next.splice(
+                    position..position + replacement.to.len(),
+                    [replacement.from.clone()],
+                );
+                (!seen.contains(&next)).then_some(next)
+            });
+        let Some(next) = next else {
+            return Ok(None);
+        };
+        seen.insert(next.clone());
+        current = next;
+        steps += 1;
+    }
+    Ok(Some(steps))
+}
+
+// Greedy reduction, trying the longest replacements first. If that ordering
+// hits a dead end, the rules are rotated and the reduction starts over.
+pub fn count_synthesis_steps(
+    replacements: &[Replacement],
+    molecule: &[String],
+    budget: &Budget,
+) -> Result<Option<usize>, AocError> {
+    let mut ordered: Vec<&Replacement> = replacements.iter().collect();
+    ordered.sort_by_key(|x| Reverse(x.to.len()));
+    for _ in 0..ordered.len() {
+        if let Some(steps) = reduce(&ordered, molecule, budget)? {
+            return Ok(Some(steps));
+        }
+        ordered.rotate_left(1);
+    }
+    Ok(None)
+}
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        let (replacements, molecule) = parse_input(input)?;
+        Ok(calibrate(&replacements, &molecule).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        self.part2_within(input, &Budget::unlimited())
+    }
+
+    fn part2_within(&self, input: &str, budget: &Budget) -> Answer {
+        let (replacements, molecule) = parse_input(input)?;
+        match count_synthesis_steps(&replacements, &molecule, budget)? {
+            Some(steps) => Ok(steps.to_string()),
+            None => Err(AocError::NoSolution(String::from(
+                "the medicine can't be made from e",
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "e => H
+                           e => O
+                           H => HO
+                           H => OH
+                           O => HH
+
+                           HOH";
+
+    fn steps(replacements: &[Replacement], molecule: &[String]) -> Option<usize> {
+        count_synthesis_steps(replacements, molecule, &Budget::unlimited()).unwrap()
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            tokenize("CRnCaSi"),
+            Ok(vec![
+                String::from("C"),
+                String::from("Rn"),
+                String::from("Ca"),
+                String::from("Si")
+            ])
+        );
+        assert_eq!(tokenize("e"), Ok(vec![String::from("e")]));
+        assert_eq!(tokenize("").map(|x| x.len()), Ok(0));
+        assert!(matches!(tokenize("HOH1"), Err(AocError::Parse(_))));
+        assert!(matches!(tokenize("aH"), Err(AocError::Parse(_))));
+        assert!(matches!(tokenize("H O"), Err(AocError::Parse(_))));
+    }
+
+    #[test]
+    fn test_replacement_from_string() {
+        assert_eq!(
+            "Ca => PRnFAr".parse::<Replacement>(),
+            Ok(Replacement {
+                from: String::from("Ca"),
+                to: tokenize("PRnFAr").unwrap()
+            })
+        );
+        assert!("Ca => PRnfAr".parse::<Replacement>().is_err());
+        assert!("Ca -> P".parse::<Replacement>().is_err());
+    }
+
+    #[test]
+    fn test_calibrate() {
+        let (replacements, molecule) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(molecule, tokenize("HOH").unwrap());
+        assert_eq!(calibrate(&replacements, &molecule), 4);
+        assert_eq!(calibrate(&replacements, &tokenize("HOHOHO").unwrap()), 7);
+        // `C` must not match the first letter of `Ca`
+        let replacements = vec!["C => HH".parse().unwrap()];
+        assert_eq!(calibrate(&replacements, &tokenize("CaC").unwrap()), 1);
+    }
+
+    #[test]
+    fn test_count_synthesis_steps() {
+        let (replacements, _) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(steps(&replacements, &tokenize("HOH").unwrap()), Some(3));
+        assert_eq!(steps(&replacements, &tokenize("HOHOHO").unwrap()), Some(6));
+        assert_eq!(steps(&replacements, &tokenize("e").unwrap()), Some(0));
+        assert_eq!(steps(&replacements, &tokenize("Ca").unwrap()), None);
+    }
+
+    #[test]
+    fn test_reduce_cyclic_rules() {
+        let replacements: Vec<Replacement> = ["e => A", "A => B", "B => A"]
+            .iter()
+            .map(|x| x.parse().unwrap())
+            .collect();
+        let ordered: Vec<&Replacement> = replacements.iter().collect();
+        assert_eq!(
+            reduce(&ordered, &tokenize("B").unwrap(), &Budget::unlimited()),
+            Ok(Some(2))
+        );
+        assert_eq!(
+            reduce(&ordered[1..], &tokenize("B").unwrap(), &Budget::unlimited()),
+            Ok(None)
+        );
+        assert_eq!(steps(&replacements, &tokenize("BB").unwrap()), None);
+    }
+
+    #[test]
+    fn test_count_synthesis_steps_gives_up() {
+        let (replacements, _) = parse_input(EXAMPLE).unwrap();
+        let budget = Budget::unlimited();
+        budget.cancel();
+        assert_eq!(
+            count_synthesis_steps(&replacements, &tokenize("HOH").unwrap(), &budget),
+            Err(AocError::Timeout(String::from("cancelled")))
+        );
+    }
+}
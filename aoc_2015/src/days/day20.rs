@@ -0,0 +1,145 @@
+use aoc_common::{Answer, AocError, Solver};
+
+pub const PRESENTS_PER_ELF: u32 = 10;
+pub const LAZY_PRESENTS_PER_ELF: u32 = 11;
+pub const LAZY_HOUSE_LIMIT: usize = 50;
+// Puzzle targets are in the tens of millions. The sieve needs one u64 per
+// house up to target / 10, this keeps it under 100MB
+pub const MAX_TARGET: u32 = 100_000_000;
+
+// Sieve of the presents delivered to houses 1..=bound. Elf `n` visits every
+// multiple of `n`, stopping after `house_limit` houses when there is one.
+// Index 0 is unused so house numbers match indexes.
+pub fn presents_by_house(bound: usize, per_elf: u32, house_limit: Option<usize>) -> Vec<u64> {
+    let mut presents = vec![0u64; bound + 1];
+    for elf in 1..=bound {
+        let last_house = match house_limit {
+            Some(limit) => bound.min(elf.saturating_mul(limit)),
+            None => bound,
+        };
+        for house in (elf..=last_house).step_by(elf) {
+            presents[house] += elf as u64 * per_elf as u64;
+        }
+    }
+    presents
+}
+
+pub fn find_lowest_house(target: u32, per_elf: u32, house_limit: Option<usize>) -> Option<usize> {
+    if target == 0 {
+        return Some(1);
+    }
+    // elves bringing nothing never reach any other target
+    if per_elf == 0 {
+        return None;
+    }
+    // house h always gets at least h * per_elf from its own elf, so the
+    // answer can never be past target / per_elf, rounded up
+    let bound = target.div_ceil(per_elf).max(1) as usize;
+    presents_by_house(bound, per_elf, house_limit)
+        .iter()
+        .skip(1)
+        .position(|x| *x >= target as u64)
+        .map(|x| x + 1)
+}
+
+pub fn parse_target(input: &str) -> Result<u32, AocError> {
+    let target = input
+        .trim()
+        .parse()
+        .map_err(|_| AocError::Parse(format!("invalid present target: {}", input.trim())))?;
+    if target > MAX_TARGET {
+        return Err(AocError::Parse(format!(
+            "present target {} is over the limit of {}",
+            target, MAX_TARGET
+        )));
+    }
+    Ok(target)
+}
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        let target = parse_target(input)?;
+        match find_lowest_house(target, PRESENTS_PER_ELF, None) {
+            Some(house) => Ok(house.to_string()),
+            None => Err(AocError::NoSolution(format!(
+                "no house gets {} presents",
+                target
+            ))),
+        }
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        let target = parse_target(input)?;
+        match find_lowest_house(target, LAZY_PRESENTS_PER_ELF, Some(LAZY_HOUSE_LIMIT)) {
+            Some(house) => Ok(house.to_string()),
+            None => Err(AocError::NoSolution(format!(
+                "no house gets {} presents from lazy elves",
+                target
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presents_by_house() {
+        assert_eq!(
+            presents_by_house(9, PRESENTS_PER_ELF, None),
+            vec![0, 10, 30, 40, 70, 60, 120, 80, 150, 130]
+        );
+        // elves 1 and 2 stop after two houses
+        assert_eq!(presents_by_house(6, 1, Some(2)), vec![0, 1, 3, 3, 6, 5, 9]);
+        // the sum of a house's elves times per_elf doesn't fit a u32
+        assert_eq!(presents_by_house(2, u32::MAX, None)[2], 3 * u32::MAX as u64);
+    }
+
+    #[test]
+    fn test_parse_target() {
+        assert_eq!(parse_target("29000000\n"), Ok(29000000));
+        assert_eq!(parse_target(&MAX_TARGET.to_string()), Ok(MAX_TARGET));
+        assert!(matches!(
+            parse_target(&(MAX_TARGET + 1).to_string()),
+            Err(AocError::Parse(_))
+        ));
+        assert!(parse_target("4294967295").is_err());
+        assert!(parse_target("lots").is_err());
+    }
+
+    #[test]
+    fn test_find_lowest_house() {
+        assert_eq!(find_lowest_house(70, PRESENTS_PER_ELF, None), Some(4));
+        assert_eq!(find_lowest_house(100, PRESENTS_PER_ELF, None), Some(6));
+        assert_eq!(find_lowest_house(130, PRESENTS_PER_ELF, None), Some(8));
+        assert_eq!(find_lowest_house(10, PRESENTS_PER_ELF, None), Some(1));
+        assert_eq!(find_lowest_house(0, PRESENTS_PER_ELF, None), Some(1));
+        // the bound has to round up to reach house 2
+        assert_eq!(find_lowest_house(15, PRESENTS_PER_ELF, None), Some(2));
+        assert_eq!(
+            find_lowest_house(12, LAZY_PRESENTS_PER_ELF, Some(LAZY_HOUSE_LIMIT)),
+            Some(2)
+        );
+        assert_eq!(find_lowest_house(10, 0, None), None);
+        assert_eq!(find_lowest_house(0, 0, None), Some(1));
+    }
+
+    #[test]
+    fn test_find_lowest_house_lazy_elves() {
+        assert_eq!(
+            find_lowest_house(132, LAZY_PRESENTS_PER_ELF, Some(LAZY_HOUSE_LIMIT)),
+            Some(6)
+        );
+        // past house 50 elf 1 is gone: house 51 gets 11 * (51 + 17 + 3) instead of 11 * 72
+        let presents = presents_by_house(60, LAZY_PRESENTS_PER_ELF, Some(LAZY_HOUSE_LIMIT));
+        assert_eq!(presents[51], 11 * 71);
+        assert_eq!(presents[50], 11 * 93);
+    }
+}
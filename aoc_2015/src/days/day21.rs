@@ -0,0 +1,304 @@
+use aoc_common::{Answer, AocError, Solver};
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::str::FromStr;
+
+pub const PLAYER_HIT_POINTS: u32 = 100;
+
+// The shop, as printed in the puzzle
+pub const SHOP: &str = "Weapons:    Cost  Damage  Armor
+Dagger        8     4       0
+Shortsword   10     5       0
+Warhammer    25     6       0
+Longsword    40     7       0
+Greataxe     74     8       0
+
+Armor:      Cost  Damage  Armor
+Leather      13     0       1
+Chainmail    31     0       2
+Splintmail   53     0       3
+Bandedmail   75     0       4
+Platemail   102     0       5
+
+Rings:      Cost  Damage  Armor
+Damage +1    25     1       0
+Damage +2    50     2       0
+Damage +3   100     3       0
+Defense +1   20     0       1
+Defense +2   40     0       2
+Defense +3   80     0       3";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Item {
+    pub name: String,
+    pub cost: u32,
+    pub damage: u32,
+    pub armor: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, Default)]
+pub struct Shop {
+    pub weapons: Vec<Item>,
+    pub armors: Vec<Item>,
+    pub rings: Vec<Item>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Fighter {
+    pub hit_points: u32,
+    pub damage: u32,
+    pub armor: u32,
+}
+
+impl From<&str> for Shop {
+    fn from(data: &str) -> Self {
+        lazy_static! {
+            static ref SECTION_RE: Regex = Regex::new(r"^([A-Za-z]+):").unwrap();
+            static ref ITEM_RE: Regex =
+                Regex::new(r"^(.+?)\s+([0-9]+)\s+([0-9]+)\s+([0-9]+)$").unwrap();
+        }
+        let mut shop = Shop::default();
+        let mut section = String::new();
+        for line in data.lines().map(|x| x.trim()).filter(|x| !x.is_empty()) {
+            if let Some(cap) = SECTION_RE.captures(line) {
+                section = cap[1].to_string();
+                continue;
+            }
+            let cap = match ITEM_RE.captures(line) {
+                Some(cap) => cap,
+                None => panic!("invalid shop item: {}", line),
+            };
+            let item = Item {
+                name: cap[1].to_string(),
+                cost: cap[2].parse().unwrap(),
+                damage: cap[3].parse().unwrap(),
+                armor: cap[4].parse().unwrap(),
+            };
+            match section.as_str() {
+                "Weapons" => shop.weapons.push(item),
+                "Armor" => shop.armors.push(item),
+                "Rings" => shop.rings.push(item),
+                _ => panic!("unknown shop section: {}", section),
+            }
+        }
+        shop
+    }
+}
+
+impl FromStr for Fighter {
+    type Err = AocError;
+
+    fn from_str(data: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref STAT_RE: Regex = Regex::new(r"^([A-Za-z ]+):\s*([0-9]+)$").unwrap();
+        }
+        let (mut hit_points, mut damage, mut armor) = (None, None, None);
+        for line in data.lines().map(|x| x.trim()).filter(|x| !x.is_empty()) {
+            let invalid = || AocError::Parse(format!("invalid stat: {}", line));
+            let cap = STAT_RE.captures(line).ok_or_else(invalid)?;
+            let value = cap[2].parse().map_err(|_| invalid())?;
+            let stat = match &cap[1] {
+                "Hit Points" => &mut hit_points,
+                "Damage" => &mut damage,
+                "Armor" => &mut armor,
+                _ => return Err(AocError::Parse(format!("unknown stat: {}", &cap[1]))),
+            };
+            if stat.replace(value).is_some() {
+                return Err(AocError::Parse(format!("repeated stat: {}", &cap[1])));
+            }
+        }
+        let missing = |name: &str| AocError::Parse(format!("missing stat: {}", name));
+        Ok(Fighter {
+            hit_points: hit_points.ok_or_else(|| missing("Hit Points"))?,
+            damage: damage.ok_or_else(|| missing("Damage"))?,
+            armor: armor.ok_or_else(|| missing("Armor"))?,
+        })
+    }
+}
+
+// Every legal loadout: exactly one weapon, at most one armor and at most two
+// different rings.
+pub fn enumerate_loadouts(shop: &Shop) -> Vec<Vec<&Item>> {
+    let armors: Vec<Option<&Item>> = std::iter::once(None)
+        .chain(shop.armors.iter().map(Some))
+        .collect();
+    let rings: Vec<Vec<&Item>> = (0..=2)
+        .flat_map(|count| shop.rings.iter().combinations(count))
+        .collect();
+    let mut loadouts = Vec::new();
+    for weapon in &shop.weapons {
+        for armor in &armors {
+            for ring_set in &rings {
+                let mut loadout = vec![weapon];
+                loadout.extend(armor);
+                loadout.extend(ring_set);
+                loadouts.push(loadout);
+            }
+        }
+    }
+    loadouts
+}
+
+pub fn calculate_cost(loadout: &[&Item]) -> u32 {
+    loadout.iter().map(|x| x.cost).sum()
+}
+
+pub fn equip(hit_points: u32, loadout: &[&Item]) -> Fighter {
+    Fighter {
+        hit_points,
+        damage: loadout.iter().map(|x| x.damage).sum(),
+        armor: loadout.iter().map(|x| x.armor).sum(),
+    }
+}
+
+pub fn turns_to_defeat(attacker: &Fighter, defender: &Fighter) -> u32 {
+    let damage = attacker.damage.saturating_sub(defender.armor).max(1);
+    defender.hit_points.div_ceil(damage)
+}
+
+// the player always attacks first, so a tie in turns is a win
+pub fn player_wins(player: &Fighter, boss: &Fighter) -> bool {
+    turns_to_defeat(player, boss) <= turns_to_defeat(boss, player)
+}
+
+// returns (cheapest winning cost, most expensive losing cost)
+pub fn find_extreme_costs(shop: &Shop, boss: &Fighter) -> (Option<u32>, Option<u32>) {
+    let mut cheapest_win = None;
+    let mut priciest_loss = None;
+    for loadout in enumerate_loadouts(shop) {
+        let cost = calculate_cost(&loadout);
+        if player_wins(&equip(PLAYER_HIT_POINTS, &loadout), boss) {
+            cheapest_win = Some(cheapest_win.map_or(cost, |x: u32| x.min(cost)));
+        } else {
+            priciest_loss = Some(priciest_loss.map_or(cost, |x: u32| x.max(cost)));
+        }
+    }
+    (cheapest_win, priciest_loss)
+}
+
+pub struct Day21;
+
+impl Solver for Day21 {
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        let (cheapest_win, _) = find_extreme_costs(&Shop::from(SHOP), &input.parse()?);
+        cheapest_win
+            .map(|cost| cost.to_string())
+            .ok_or_else(|| AocError::NoSolution(String::from("no loadout beats the boss")))
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        let (_, priciest_loss) = find_extreme_costs(&Shop::from(SHOP), &input.parse()?);
+        priciest_loss
+            .map(|cost| cost.to_string())
+            .ok_or_else(|| AocError::NoSolution(String::from("no loadout loses to the boss")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shop_from_string() {
+        let shop = Shop::from(SHOP);
+        assert_eq!(shop.weapons.len(), 5);
+        assert_eq!(shop.armors.len(), 5);
+        assert_eq!(shop.rings.len(), 6);
+        assert_eq!(
+            shop.rings[0],
+            Item {
+                name: String::from("Damage +1"),
+                cost: 25,
+                damage: 1,
+                armor: 0
+            }
+        );
+        assert_eq!(shop.armors[4].cost, 102);
+    }
+
+    #[test]
+    fn test_fighter_from_string() {
+        assert_eq!(
+            "Hit Points: 104\nDamage: 8\nArmor: 1\n".parse::<Fighter>(),
+            Ok(Fighter {
+                hit_points: 104,
+                damage: 8,
+                armor: 1
+            })
+        );
+        assert_eq!(
+            "Hit Points: 104\nArmor: 1".parse::<Fighter>(),
+            Err(AocError::Parse(String::from("missing stat: Damage")))
+        );
+        for data in [
+            "Hit Points: 104\nDamage: 8\nArmor: 1\nArmor: 2",
+            "Hit Points: 99999999999\nDamage: 8\nArmor: 1",
+            "Hit Points: 104\nDamage: 8\nArmor: 1\nSpeed: 3",
+            "Hit Points: lots",
+        ] {
+            assert!(
+                matches!(data.parse::<Fighter>(), Err(AocError::Parse(_))),
+                "{}",
+                data
+            );
+        }
+        assert!(matches!(Day21.part1(""), Err(AocError::Parse(_))));
+    }
+
+    #[test]
+    fn test_enumerate_loadouts() {
+        let shop = Shop::from(SHOP);
+        let loadouts = enumerate_loadouts(&shop);
+        // 5 weapons * 6 armor choices * (1 + 6 + 15) ring choices
+        assert_eq!(loadouts.len(), 660);
+        assert!(loadouts.iter().all(|x| !x.is_empty() && x.len() <= 4));
+        assert_eq!(loadouts.iter().map(|x| calculate_cost(x)).min(), Some(8));
+    }
+
+    #[test]
+    fn test_player_wins() {
+        let player = Fighter {
+            hit_points: 8,
+            damage: 5,
+            armor: 5,
+        };
+        let boss = Fighter {
+            hit_points: 12,
+            damage: 7,
+            armor: 2,
+        };
+        assert_eq!(turns_to_defeat(&player, &boss), 4);
+        assert_eq!(turns_to_defeat(&boss, &player), 4);
+        assert!(player_wins(&player, &boss));
+        assert!(!player_wins(
+            &Fighter {
+                hit_points: 6,
+                ..player
+            },
+            &boss
+        ));
+    }
+
+    #[test]
+    fn test_find_extreme_costs() {
+        let shop = Shop::from(SHOP);
+        // a boss nobody can lose to, and one nobody can beat
+        let weak = Fighter {
+            hit_points: 1,
+            damage: 0,
+            armor: 0,
+        };
+        assert_eq!(find_extreme_costs(&shop, &weak), (Some(8), None));
+        let strong = Fighter {
+            hit_points: 1000,
+            damage: 1000,
+            armor: 0,
+        };
+        assert_eq!(find_extreme_costs(&shop, &strong), (None, Some(356)));
+    }
+}
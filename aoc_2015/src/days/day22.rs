@@ -0,0 +1,388 @@
+use aoc_common::{Answer, AocError, Budget, Solver};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+
+pub const PLAYER_HIT_POINTS: i32 = 50;
+pub const PLAYER_MANA: i32 = 500;
+
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub enum Spell {
+    MagicMissile,
+    Drain,
+    Shield,
+    Poison,
+    Recharge,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Difficulty {
+    Normal,
+    // the player loses 1 hit point at the start of each of their turns
+    Hard,
+}
+
+// Effects are stored as the number of turns they have left
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub struct GameState {
+    pub player_hit_points: i32,
+    pub player_mana: i32,
+    pub boss_hit_points: i32,
+    pub boss_damage: i32,
+    pub shield: u8,
+    pub poison: u8,
+    pub recharge: u8,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Won,
+    Lost,
+    Ongoing(GameState),
+}
+
+pub const SPELLS: [Spell; 5] = [
+    Spell::MagicMissile,
+    Spell::Drain,
+    Spell::Shield,
+    Spell::Poison,
+    Spell::Recharge,
+];
+
+impl Spell {
+    fn cost(&self) -> i32 {
+        match *self {
+            Spell::MagicMissile => 53,
+            Spell::Drain => 73,
+            Spell::Shield => 113,
+            Spell::Poison => 173,
+            Spell::Recharge => 229,
+        }
+    }
+}
+
+impl GameState {
+    fn new(
+        player_hit_points: i32,
+        player_mana: i32,
+        boss_hit_points: i32,
+        boss_damage: i32,
+    ) -> Self {
+        GameState {
+            player_hit_points,
+            player_mana,
+            boss_hit_points,
+            boss_damage,
+            shield: 0,
+            poison: 0,
+            recharge: 0,
+        }
+    }
+
+    fn apply_effects(&mut self) {
+        if self.poison > 0 {
+            self.boss_hit_points -= 3;
+            self.poison -= 1;
+        }
+        if self.recharge > 0 {
+            self.player_mana += 101;
+            self.recharge -= 1;
+        }
+        if self.shield > 0 {
+            self.shield -= 1;
+        }
+    }
+
+    fn can_cast(&self, spell: Spell) -> bool {
+        let active = match spell {
+            Spell::Shield => self.shield > 0,
+            Spell::Poison => self.poison > 0,
+            Spell::Recharge => self.recharge > 0,
+            _ => false,
+        };
+        !active && self.player_mana >= spell.cost()
+    }
+
+    fn cast(&mut self, spell: Spell) {
+        self.player_mana -= spell.cost();
+        match spell {
+            Spell::MagicMissile => self.boss_hit_points -= 4,
+            Spell::Drain => {
+                self.boss_hit_points -= 2;
+                self.player_hit_points += 2;
+            }
+            Spell::Shield => self.shield = 6,
+            Spell::Poison => self.poison = 6,
+            Spell::Recharge => self.recharge = 5,
+        }
+    }
+
+    // The start of the player's turn: hard mode's drain, then the effects.
+    // The boss can die here, before any spell is cast
+    fn start_turn(&self, difficulty: Difficulty) -> Outcome {
+        let mut state = *self;
+        if difficulty == Difficulty::Hard {
+            state.player_hit_points -= 1;
+            if state.player_hit_points <= 0 {
+                return Outcome::Lost;
+            }
+        }
+        state.apply_effects();
+        if state.boss_hit_points <= 0 {
+            return Outcome::Won;
+        }
+        Outcome::Ongoing(state)
+    }
+
+    // The rest of the round after `start_turn`: casting `spell`, then the
+    // boss's turn. Casting a spell that is unaffordable or already active
+    // loses the game.
+    fn cast_round(&self, spell: Spell) -> Outcome {
+        let mut state = *self;
+        if !state.can_cast(spell) {
+            return Outcome::Lost;
+        }
+        state.cast(spell);
+        if state.boss_hit_points <= 0 {
+            return Outcome::Won;
+        }
+
+        let armor = if state.shield > 0 { 7 } else { 0 };
+        state.apply_effects();
+        if state.boss_hit_points <= 0 {
+            return Outcome::Won;
+        }
+        state.player_hit_points -= (state.boss_damage - armor).max(1);
+        if state.player_hit_points <= 0 {
+            return Outcome::Lost;
+        }
+        Outcome::Ongoing(state)
+    }
+}
+
+pub fn parse_boss(boss_str: &str) -> (i32, i32) {
+    lazy_static! {
+        static ref BOSS_RE: Regex =
+            Regex::new(r"Hit Points:\s*([0-9]+)\s+Damage:\s*([0-9]+)").unwrap();
+    }
+    match BOSS_RE.captures(boss_str) {
+        Some(cap) => (cap[1].parse().unwrap(), cap[2].parse().unwrap()),
+        None => panic!("invalid boss stats: {}", boss_str),
+    }
+}
+
+// Dijkstra over game states, weighted by mana spent. Returns the minimum mana
+// needed to win and the spells cast, in order. Wins are queued like any other
+// state, so the first one popped is the cheapest. A state whose start of turn
+// effects kill the boss is a win at what was spent to reach it.
+pub fn find_cheapest_win(
+    start: GameState,
+    difficulty: Difficulty,
+    budget: &Budget,
+) -> Result<Option<(i32, Vec<Spell>)>, AocError> {
+    let mut queue = BinaryHeap::new();
+    let mut visited = HashSet::new();
+    queue.push(Reverse((0, false, start, Vec::new())));
+    while let Some(Reverse((spent, won, state, spells))) = queue.pop() {
+        budget.check()?;
+        if won {
+            return Ok(Some((spent, spells)));
+        }
+        if !visited.insert(state) {
+            continue;
+        }
+        let turn = match state.start_turn(difficulty) {
+            Outcome::Won => return Ok(Some((spent, spells))),
+            Outcome::Lost => continue,
+            Outcome::Ongoing(turn) => turn,
+        };
+        for spell in SPELLS {
+            let mut next_spells = spells.clone();
+            next_spells.push(spell);
+            match turn.cast_round(spell) {
+                Outcome::Won => {
+                    queue.push(Reverse((spent + spell.cost(), true, turn, next_spells)))
+                }
+                Outcome::Ongoing(next) if !visited.contains(&next) => {
+                    queue.push(Reverse((spent + spell.cost(), false, next, next_spells)))
+                }
+                _ => (),
+            }
+        }
+    }
+    Ok(None)
+}
+
+pub fn solve(
+    input: &str,
+    difficulty: Difficulty,
+    budget: &Budget,
+) -> Result<Option<(i32, Vec<Spell>)>, AocError> {
+    let (boss_hit_points, boss_damage) = parse_boss(input);
+    let start = GameState::new(PLAYER_HIT_POINTS, PLAYER_MANA, boss_hit_points, boss_damage);
+    find_cheapest_win(start, difficulty, budget)
+}
+
+pub struct Day22;
+
+impl Solver for Day22 {
+    fn day(&self) -> u8 {
+        22
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        self.part1_within(input, &Budget::unlimited())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        self.part2_within(input, &Budget::unlimited())
+    }
+
+    fn part1_within(&self, input: &str, budget: &Budget) -> Answer {
+        match solve(input, Difficulty::Normal, budget)? {
+            Some((mana, _)) => Ok(mana.to_string()),
+            None => Err(AocError::NoSolution(String::from(
+                "the boss can't be beaten",
+            ))),
+        }
+    }
+
+    fn part2_within(&self, input: &str, budget: &Budget) -> Answer {
+        match solve(input, Difficulty::Hard, budget)? {
+            Some((mana, _)) => Ok(mana.to_string()),
+            None => Err(AocError::NoSolution(String::from(
+                "the boss can't be beaten",
+            ))),
+        }
+    }
+
+    // `spells` also lists the spells cast in the cheapest win
+    fn run_mode(&self, input: &str, args: &[String]) -> Answer {
+        let spells = args.iter().any(|x| x == "spells");
+        let mut output = Vec::new();
+        for difficulty in [Difficulty::Normal, Difficulty::Hard] {
+            match solve(input, difficulty, &Budget::unlimited())? {
+                Some((mana, cast)) => {
+                    output.push(format!("{:?}: least mana to win is {}", difficulty, mana));
+                    if spells {
+                        output.push(format!("{:?}: spells cast {:?}", difficulty, cast));
+                    }
+                }
+                None => output.push(format!("{:?}: the boss can't be beaten", difficulty)),
+            }
+        }
+        Ok(output.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a full round: the player's turn casting `spell`, then the boss's turn
+    fn play_round(state: &GameState, spell: Spell, difficulty: Difficulty) -> Outcome {
+        match state.start_turn(difficulty) {
+            Outcome::Ongoing(state) => state.cast_round(spell),
+            outcome => outcome,
+        }
+    }
+
+    fn cheapest(start: GameState, difficulty: Difficulty) -> Option<(i32, Vec<Spell>)> {
+        find_cheapest_win(start, difficulty, &Budget::unlimited()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_boss() {
+        assert_eq!(parse_boss("Hit Points: 58\nDamage: 9\n"), (58, 9));
+    }
+
+    #[test]
+    fn test_play_round() {
+        // first example from the puzzle: Poison, then Magic Missile
+        let start = GameState::new(10, 250, 13, 8);
+        let state = match play_round(&start, Spell::Poison, Difficulty::Normal) {
+            Outcome::Ongoing(state) => state,
+            outcome => panic!("unexpected {:?}", outcome),
+        };
+        assert_eq!(state.player_hit_points, 2);
+        assert_eq!(state.player_mana, 77);
+        assert_eq!(state.boss_hit_points, 10);
+        assert_eq!(state.poison, 5);
+        assert_eq!(
+            play_round(&state, Spell::MagicMissile, Difficulty::Normal),
+            Outcome::Won
+        );
+        // Poison is still active, so it can't be cast again
+        assert_eq!(
+            play_round(&state, Spell::Poison, Difficulty::Normal),
+            Outcome::Lost
+        );
+        assert_eq!(
+            play_round(
+                &GameState::new(1, 250, 13, 8),
+                Spell::Poison,
+                Difficulty::Hard
+            ),
+            Outcome::Lost
+        );
+    }
+
+    #[test]
+    fn test_effects() {
+        let mut state = GameState::new(10, 250, 14, 8);
+        state.cast(Spell::Recharge);
+        state.cast(Spell::Shield);
+        assert_eq!(state.player_mana, 250 - 229 - 113);
+        state.apply_effects();
+        assert_eq!(state.player_mana, 250 - 229 - 113 + 101);
+        assert_eq!(state.recharge, 4);
+        assert_eq!(state.shield, 5);
+    }
+
+    #[test]
+    fn test_find_cheapest_win() {
+        let (mana, spells) = cheapest(GameState::new(10, 250, 13, 8), Difficulty::Normal).unwrap();
+        assert_eq!(mana, 226);
+        assert_eq!(spells, vec![Spell::Poison, Spell::MagicMissile]);
+
+        let (mana, spells) = cheapest(GameState::new(10, 250, 14, 8), Difficulty::Normal).unwrap();
+        assert_eq!(mana, 641);
+        assert_eq!(spells.iter().map(|x| x.cost()).sum::<i32>(), mana);
+
+        assert_eq!(
+            cheapest(GameState::new(1, 10, 14, 8), Difficulty::Normal),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_cheapest_win_poison_finishes() {
+        // the last poison tick, at the start of the player's fourth turn,
+        // kills the boss without a fourth spell
+        let (mana, spells) = cheapest(GameState::new(50, 500, 24, 8), Difficulty::Normal).unwrap();
+        assert_eq!(mana, 279);
+        assert_eq!(
+            spells,
+            vec![Spell::Poison, Spell::MagicMissile, Spell::MagicMissile]
+        );
+    }
+
+    #[test]
+    fn test_find_cheapest_win_hard_mode() {
+        let normal = cheapest(GameState::new(50, 500, 51, 9), Difficulty::Normal).unwrap();
+        let hard = cheapest(GameState::new(50, 500, 51, 9), Difficulty::Hard).unwrap();
+        assert_eq!(normal.0, 900);
+        assert_eq!(hard.0, 1216);
+    }
+
+    #[test]
+    fn test_find_cheapest_win_gives_up() {
+        let budget = Budget::unlimited();
+        budget.cancel();
+        assert_eq!(
+            find_cheapest_win(GameState::new(50, 500, 51, 9), Difficulty::Hard, &budget),
+            Err(AocError::Timeout(String::from("cancelled")))
+        );
+    }
+}
@@ -0,0 +1,329 @@
+use aoc_common::{Answer, AocError, Solver};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::fmt;
+
+// guards against programs that never reach the end
+pub const INSTRUCTION_LIMIT: u64 = 10_000_000;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Register {
+    A,
+    B,
+}
+
+// named after the mnemonics, like the day 7 gate operations
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Instruction {
+    HLF(Register),
+    TPL(Register),
+    INC(Register),
+    JMP(i64),
+    JIE(Register, i64),
+    JIO(Register, i64),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Registers {
+    pub a: u64,
+    pub b: u64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum VmError {
+    InstructionLimit(u64),
+    // the register went past u64::MAX
+    Overflow(Register),
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VmError::InstructionLimit(limit) => {
+                write!(f, "no end after {} instructions", limit)
+            }
+            VmError::Overflow(Register::A) => write!(f, "register a overflowed"),
+            VmError::Overflow(Register::B) => write!(f, "register b overflowed"),
+        }
+    }
+}
+
+// a program that can't finish has no answer
+impl From<VmError> for AocError {
+    fn from(error: VmError) -> Self {
+        AocError::NoSolution(error.to_string())
+    }
+}
+
+impl Registers {
+    fn get(&self, register: Register) -> u64 {
+        match register {
+            Register::A => self.a,
+            Register::B => self.b,
+        }
+    }
+
+    fn get_mut(&mut self, register: Register) -> &mut u64 {
+        match register {
+            Register::A => &mut self.a,
+            Register::B => &mut self.b,
+        }
+    }
+}
+
+impl From<&str> for Instruction {
+    fn from(data: &str) -> Self {
+        lazy_static! {
+            static ref INSTRUCTION_RE: Regex =
+                Regex::new(r"^(hlf|tpl|inc|jmp|jie|jio)\s+(?:([ab])(?:,\s*)?)?([+-][0-9]+)?$")
+                    .unwrap();
+        }
+        let cap = match INSTRUCTION_RE.captures(data.trim()) {
+            Some(cap) => cap,
+            None => panic!("invalid instruction: {}", data),
+        };
+        let register = cap.get(2).map(|x| match x.as_str() {
+            "a" => Register::A,
+            _ => Register::B,
+        });
+        let offset = cap.get(3).map(|x| x.as_str().parse::<i64>().unwrap());
+        match (&cap[1], register, offset) {
+            ("hlf", Some(register), None) => Instruction::HLF(register),
+            ("tpl", Some(register), None) => Instruction::TPL(register),
+            ("inc", Some(register), None) => Instruction::INC(register),
+            ("jmp", None, Some(offset)) => Instruction::JMP(offset),
+            ("jie", Some(register), Some(offset)) => Instruction::JIE(register, offset),
+            ("jio", Some(register), Some(offset)) => Instruction::JIO(register, offset),
+            _ => panic!("invalid operands: {}", data),
+        }
+    }
+}
+
+impl Instruction {
+    // returns the offset to the next instruction
+    fn execute(&self, registers: &mut Registers) -> Result<i64, VmError> {
+        match *self {
+            Instruction::HLF(register) => *registers.get_mut(register) /= 2,
+            Instruction::TPL(register) => {
+                let value = registers.get_mut(register);
+                *value = value.checked_mul(3).ok_or(VmError::Overflow(register))?;
+            }
+            Instruction::INC(register) => {
+                let value = registers.get_mut(register);
+                *value = value.checked_add(1).ok_or(VmError::Overflow(register))?;
+            }
+            Instruction::JMP(offset) => return Ok(offset),
+            Instruction::JIE(register, offset) => {
+                if registers.get(register).is_multiple_of(2) {
+                    return Ok(offset);
+                }
+            }
+            Instruction::JIO(register, offset) => {
+                if registers.get(register) == 1 {
+                    return Ok(offset);
+                }
+            }
+        }
+        Ok(1)
+    }
+}
+
+pub fn parse_program(program_str: &str) -> Vec<Instruction> {
+    program_str
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(Instruction::from)
+        .collect()
+}
+
+// Runs until the program counter leaves the program. `on_step` sees the
+// program counter, the instruction and the registers after it ran.
+pub fn run<F>(
+    program: &[Instruction],
+    initial: Registers,
+    limit: u64,
+    mut on_step: F,
+) -> Result<Registers, VmError>
+where
+    F: FnMut(usize, &Instruction, &Registers),
+{
+    let mut registers = initial;
+    let mut counter: i64 = 0;
+    let mut executed = 0;
+    while counter >= 0 && (counter as usize) < program.len() {
+        if executed == limit {
+            return Err(VmError::InstructionLimit(limit));
+        }
+        let instruction = &program[counter as usize];
+        let offset = instruction.execute(&mut registers)?;
+        on_step(counter as usize, instruction, &registers);
+        counter += offset;
+        executed += 1;
+    }
+    Ok(registers)
+}
+
+pub fn register_b(input: &str, initial: Registers) -> Answer {
+    let program = parse_program(input);
+    let registers = run(&program, initial, INSTRUCTION_LIMIT, |_, _, _| {})?;
+    Ok(registers.b.to_string())
+}
+
+pub struct Day23;
+
+impl Solver for Day23 {
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        register_b(input, Registers { a: 0, b: 0 })
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        register_b(input, Registers { a: 1, b: 0 })
+    }
+
+    // `trace` prints every executed instruction
+    fn run_mode(&self, input: &str, args: &[String]) -> Answer {
+        let trace = args.iter().any(|x| x == "trace");
+        let program = parse_program(input);
+        let mut output = Vec::new();
+        for initial in [Registers { a: 0, b: 0 }, Registers { a: 1, b: 0 }] {
+            let result = run(
+                &program,
+                initial,
+                INSTRUCTION_LIMIT,
+                |counter, instruction, registers| {
+                    if trace {
+                        output.push(format!("{:>4} {:?} {:?}", counter, instruction, registers));
+                    }
+                },
+            );
+            output.push(match result {
+                Ok(registers) => format!("starting from {:?}, b is {}", initial, registers.b),
+                Err(why) => format!("starting from {:?}: {}", initial, why),
+            });
+        }
+        Ok(output.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instruction_from_string() {
+        assert_eq!(Instruction::from("hlf a"), Instruction::HLF(Register::A));
+        assert_eq!(Instruction::from("tpl b"), Instruction::TPL(Register::B));
+        assert_eq!(Instruction::from("inc a"), Instruction::INC(Register::A));
+        assert_eq!(Instruction::from("jmp -7"), Instruction::JMP(-7));
+        assert_eq!(
+            Instruction::from("jie a, +4"),
+            Instruction::JIE(Register::A, 4)
+        );
+        assert_eq!(
+            Instruction::from("jio b, -2"),
+            Instruction::JIO(Register::B, -2)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_instruction_missing_offset() {
+        let _ = Instruction::from("jie a");
+    }
+
+    #[test]
+    fn test_execute() {
+        let mut registers = Registers { a: 6, b: 1 };
+        assert_eq!(Instruction::HLF(Register::A).execute(&mut registers), Ok(1));
+        assert_eq!(registers.a, 3);
+        assert_eq!(Instruction::TPL(Register::A).execute(&mut registers), Ok(1));
+        assert_eq!(registers.a, 9);
+        assert_eq!(Instruction::INC(Register::B).execute(&mut registers), Ok(1));
+        assert_eq!(registers.b, 2);
+        assert_eq!(
+            Instruction::JIE(Register::A, 5).execute(&mut registers),
+            Ok(1)
+        );
+        assert_eq!(
+            Instruction::JIE(Register::B, 5).execute(&mut registers),
+            Ok(5)
+        );
+        assert_eq!(
+            Instruction::JIO(Register::B, 5).execute(&mut registers),
+            Ok(1)
+        );
+        assert_eq!(Instruction::JMP(-3).execute(&mut registers), Ok(-3));
+    }
+
+    #[test]
+    fn test_run() {
+        let program = parse_program("inc a\njio a, +2\ntpl a\ninc a");
+        let registers = run(&program, Registers::default(), 100, |_, _, _| {}).unwrap();
+        assert_eq!(registers.a, 2);
+        let registers = run(&program, Registers { a: 1, b: 0 }, 100, |_, _, _| {}).unwrap();
+        assert_eq!(registers.a, 7);
+    }
+
+    #[test]
+    fn test_run_trace() {
+        let program = parse_program("inc a\njio a, +2\ntpl a\ninc a");
+        let mut trace = Vec::new();
+        run(
+            &program,
+            Registers::default(),
+            100,
+            |counter, _, registers| trace.push((counter, registers.a)),
+        )
+        .unwrap();
+        assert_eq!(trace, vec![(0, 1), (1, 1), (3, 2)]);
+    }
+
+    #[test]
+    fn test_run_instruction_limit() {
+        let program = parse_program("inc a\njmp -1");
+        assert_eq!(
+            run(&program, Registers::default(), 1000, |_, _, _| {}),
+            Err(VmError::InstructionLimit(1000))
+        );
+    }
+
+    #[test]
+    fn test_run_overflow() {
+        // runs away long before the instruction limit
+        let program = parse_program("inc a\ntpl a\njmp -1");
+        assert_eq!(
+            run(&program, Registers::default(), 1000, |_, _, _| {}),
+            Err(VmError::Overflow(Register::A))
+        );
+        let mut registers = Registers { a: 0, b: u64::MAX };
+        assert_eq!(
+            Instruction::INC(Register::B).execute(&mut registers),
+            Err(VmError::Overflow(Register::B))
+        );
+        assert_eq!(registers.b, u64::MAX);
+    }
+
+    #[test]
+    fn test_vm_error_display() {
+        assert_eq!(
+            VmError::InstructionLimit(1000).to_string(),
+            "no end after 1000 instructions"
+        );
+        assert_eq!(
+            AocError::from(VmError::Overflow(Register::B)),
+            AocError::NoSolution(String::from("register b overflowed"))
+        );
+        assert_eq!(
+            register_b("inc b\njmp -1", Registers::default()),
+            Err(AocError::NoSolution(format!(
+                "no end after {} instructions",
+                INSTRUCTION_LIMIT
+            )))
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use aoc_common::parse::parse_numbers;
+use aoc_common::{Answer, AocError, Budget, Solver};
+use itertools::Itertools;
+
+pub fn parse_weights(weights_str: &str) -> Result<Vec<u64>, AocError> {
+    parse_numbers(weights_str)
+}
+
+pub fn quantum_entanglement(group: &[u64]) -> u128 {
+    group.iter().map(|x| *x as u128).product()
+}
+
+// Backtracking check that `weights` fill `groups` bins of exactly `target`
+pub fn can_split(weights: &[u64], groups: usize, target: u64) -> bool {
+    fn fill(weights: &[u64], bins: &mut [u64], target: u64) -> bool {
+        let (weight, rest) = match weights.split_first() {
+            Some(split) => split,
+            None => return bins.iter().all(|x| *x == target),
+        };
+        for index in 0..bins.len() {
+            // an empty bin is the same as any other empty bin, only try one
+            if bins[index] + weight > target || (bins[index] == 0 && bins[..index].contains(&0)) {
+                continue;
+            }
+            bins[index] += weight;
+            if fill(rest, bins, target) {
+                return true;
+            }
+            bins[index] -= weight;
+        }
+        false
+    }
+
+    let mut sorted = weights.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    fill(&sorted, &mut vec![0; groups], target)
+}
+
+// combinations are cheap to try, the budget only needs a look now and then
+const CHECK_EVERY: usize = 4096;
+
+// Splits the packages into `groups` groups of equal weight. Returns the
+// quantum entanglement of the first group, which must have the fewest
+// packages, breaking ties by the smallest entanglement. Combinations are
+// walked one at a time and the search stops at the first size that works.
+pub fn balance_sleigh(
+    weights: &[u64],
+    groups: usize,
+    budget: &Budget,
+) -> Result<Option<u128>, AocError> {
+    let total: u64 = weights.iter().sum();
+    if groups == 0 || !total.is_multiple_of(groups as u64) {
+        return Ok(None);
+    }
+    let target = total / groups as u64;
+    for size in 1..=weights.len() {
+        let mut best: Option<u128> = None;
+        for (index, candidate) in (0..weights.len()).combinations(size).enumerate() {
+            if index % CHECK_EVERY == 0 {
+                budget.check()?;
+            }
+            if candidate.iter().map(|x| weights[*x]).sum::<u64>() != target {
+                continue;
+            }
+            let group: Vec<u64> = candidate.iter().map(|x| weights[*x]).collect();
+            // only a better entanglement is worth the split check
+            let entanglement = quantum_entanglement(&group);
+            if best.is_some_and(|x| x <= entanglement) {
+                continue;
+            }
+            let rest: Vec<u64> = (0..weights.len())
+                .filter(|x| !candidate.contains(x))
+                .map(|x| weights[x])
+                .collect();
+            if can_split(&rest, groups - 1, target) {
+                best = Some(entanglement);
+            }
+        }
+        if best.is_some() {
+            return Ok(best);
+        }
+    }
+    Ok(None)
+}
+
+pub fn solve(input: &str, groups: usize, budget: &Budget) -> Answer {
+    match balance_sleigh(&parse_weights(input)?, groups, budget)? {
+        Some(entanglement) => Ok(entanglement.to_string()),
+        None => Err(AocError::NoSolution(format!(
+            "packages can't be split into {} groups",
+            groups
+        ))),
+    }
+}
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        self.part1_within(input, &Budget::unlimited())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        self.part2_within(input, &Budget::unlimited())
+    }
+
+    fn part1_within(&self, input: &str, budget: &Budget) -> Answer {
+        solve(input, 3, budget)
+    }
+
+    fn part2_within(&self, input: &str, budget: &Budget) -> Answer {
+        solve(input, 4, budget)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [u64; 10] = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11];
+
+    #[test]
+    fn test_quantum_entanglement() {
+        assert_eq!(quantum_entanglement(&[11, 9]), 99);
+        assert_eq!(quantum_entanglement(&[10, 9, 1]), 90);
+        // would overflow a u64
+        assert_eq!(quantum_entanglement(&[u64::MAX, 2]), u64::MAX as u128 * 2);
+    }
+
+    #[test]
+    fn test_can_split() {
+        assert!(can_split(&[1, 2, 3, 4, 5, 7, 8, 10], 2, 20));
+        assert!(!can_split(&[1, 2, 3, 4, 5, 7, 8, 10], 2, 19));
+        assert!(can_split(&[], 0, 20));
+        assert!(!can_split(&[10, 10, 1], 2, 10));
+    }
+
+    #[test]
+    fn test_balance_sleigh() {
+        let budget = Budget::unlimited();
+        assert_eq!(balance_sleigh(&EXAMPLE, 3, &budget), Ok(Some(99)));
+        assert_eq!(balance_sleigh(&EXAMPLE, 4, &budget), Ok(Some(44)));
+        assert_eq!(balance_sleigh(&EXAMPLE, 7, &budget), Ok(None));
+        assert_eq!(balance_sleigh(&[3, 3, 2, 2, 2], 2, &budget), Ok(Some(9)));
+        assert_eq!(balance_sleigh(&[3, 3, 3, 1], 2, &budget), Ok(None));
+    }
+
+    #[test]
+    fn test_balance_sleigh_gives_up() {
+        let budget = Budget::unlimited();
+        budget.cancel();
+        assert_eq!(
+            balance_sleigh(&EXAMPLE, 3, &budget),
+            Err(AocError::Timeout(String::from("cancelled")))
+        );
+    }
+}
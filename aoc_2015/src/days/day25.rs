@@ -0,0 +1,148 @@
+use aoc_common::{Answer, AocError, Solver};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub const FIRST_CODE: u64 = 20151125;
+pub const MULTIPLIER: u64 = 252533;
+pub const MODULUS: u64 = 33554393;
+
+// rows and columns count from 1
+pub fn parse_position(sentence: &str) -> Result<(u64, u64), AocError> {
+    lazy_static! {
+        static ref POSITION_RE: Regex = Regex::new(r"row ([0-9]+), column ([0-9]+)").unwrap();
+    }
+    let invalid = || AocError::Parse(format!("no row and column in: {}", sentence.trim()));
+    let cap = POSITION_RE.captures(sentence).ok_or_else(invalid)?;
+    let row: u64 = cap[1].parse().map_err(|_| invalid())?;
+    let column: u64 = cap[2].parse().map_err(|_| invalid())?;
+    if row == 0 || column == 0 || diagonal_index(row, column).is_none() {
+        return Err(AocError::Parse(format!(
+            "row {}, column {} is outside the grid",
+            row, column
+        )));
+    }
+    Ok((row, column))
+}
+
+// Codes are filled in diagonals going up and to the right, so (row, column)
+// is on diagonal row + column - 1. Returns the 1 based order of that cell,
+// or `None` when it doesn't fit in a u64.
+pub fn diagonal_index(row: u64, column: u64) -> Option<u64> {
+    let diagonal = row.checked_add(column)?.checked_sub(1)?;
+    let before = if diagonal % 2 == 0 {
+        (diagonal / 2).checked_mul(diagonal.checked_sub(1)?)?
+    } else {
+        diagonal.checked_mul((diagonal - 1) / 2)?
+    };
+    before.checked_add(column)
+}
+
+pub fn modular_pow(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1;
+    let mut base = base % modulus;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result
+}
+
+// code n is FIRST_CODE * MULTIPLIER^(n - 1), so there is no need to walk
+// through the millions of codes before it
+pub fn find_code(row: u64, column: u64) -> Result<u64, AocError> {
+    let steps = diagonal_index(row, column)
+        .and_then(|x| x.checked_sub(1))
+        .ok_or_else(|| {
+            AocError::Parse(format!(
+                "row {}, column {} is outside the grid",
+                row, column
+            ))
+        })?;
+    Ok(FIRST_CODE * modular_pow(MULTIPLIER, steps, MODULUS) % MODULUS)
+}
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        let (row, column) = parse_position(input)?;
+        find_code(row, column).map(|x| x.to_string())
+    }
+
+    // there is no second puzzle on the last day
+    fn parts(&self) -> u8 {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_position() {
+        assert_eq!(
+            parse_position(
+                "To continue, please consult the code grid in the manual.  \
+                 Enter the code at row 2947, column 3029."
+            ),
+            Ok((2947, 3029))
+        );
+        assert_eq!(
+            parse_position("row 0, column 3"),
+            Err(AocError::Parse(String::from(
+                "row 0, column 3 is outside the grid"
+            )))
+        );
+        assert!(parse_position("row 4, column 0").is_err());
+        assert!(parse_position("garbage").is_err());
+        assert!(parse_position("row 99999999999, column 99999999999").is_err());
+    }
+
+    #[test]
+    fn test_diagonal_index() {
+        assert_eq!(diagonal_index(1, 1), Some(1));
+        assert_eq!(diagonal_index(2, 1), Some(2));
+        assert_eq!(diagonal_index(1, 2), Some(3));
+        assert_eq!(diagonal_index(3, 1), Some(4));
+        assert_eq!(diagonal_index(4, 2), Some(12));
+        assert_eq!(diagonal_index(1, 5), Some(15));
+        assert_eq!(diagonal_index(99999999999, 99999999999), None);
+        assert_eq!(diagonal_index(u64::MAX, 1), None);
+        assert_eq!(diagonal_index(0, 0), None);
+    }
+
+    #[test]
+    fn test_modular_pow() {
+        assert_eq!(modular_pow(2, 10, 1000), 24);
+        assert_eq!(modular_pow(MULTIPLIER, 0, MODULUS), 1);
+        assert_eq!(modular_pow(MULTIPLIER, 1, MODULUS), MULTIPLIER);
+    }
+
+    #[test]
+    fn test_find_code() {
+        // the example table from the puzzle
+        let table: [[u64; 6]; 6] = [
+            [20151125, 18749137, 17289845, 30943339, 10071777, 33511524],
+            [31916031, 21629792, 16929656, 7726640, 15514188, 4041754],
+            [16080970, 8057251, 1601130, 7981243, 11661866, 16474243],
+            [24592653, 32451966, 21345942, 9380097, 10600672, 31527494],
+            [77061, 17552253, 28094349, 6899651, 9250759, 31663883],
+            [33071741, 6796745, 25397450, 24659492, 1534922, 27995004],
+        ];
+        for (row, codes) in table.iter().enumerate() {
+            for (column, code) in codes.iter().enumerate() {
+                assert_eq!(find_code(row as u64 + 1, column as u64 + 1), Ok(*code));
+            }
+        }
+        assert!(find_code(99999999999, 99999999999).is_err());
+    }
+}
@@ -0,0 +1,181 @@
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::ops::Add;
+
+// Weighted graph helpers shared by the route style puzzles (day 9, day 13)
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Edge<W> {
+    pub origin: String,
+    pub destination: String,
+    pub weight: W,
+}
+
+pub type WeightTable<W> = HashMap<(String, String), W>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Direction {
+    // a -> b and b -> a share the same weight
+    Undirected,
+    // a -> b and b -> a are independent edges
+    Directed,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RouteShape {
+    // visit every node once, start and end anywhere
+    Path,
+    // visit every node once and come back to the start
+    Cycle,
+}
+
+pub fn construct_weight_table<W: Copy>(edges: &[Edge<W>], direction: Direction) -> WeightTable<W> {
+    let mut table = HashMap::new();
+    for edge in edges {
+        table.insert((edge.origin.clone(), edge.destination.clone()), edge.weight);
+        if direction == Direction::Undirected {
+            table.insert((edge.destination.clone(), edge.origin.clone()), edge.weight);
+        }
+    }
+    table
+}
+
+// Collapses a directed table into an undirected one, where every pair carries
+// the sum of both directions. Missing directions count as the default weight.
+pub fn symmetrize<W>(table: &WeightTable<W>) -> WeightTable<W>
+where
+    W: Copy + Default + Add<Output = W>,
+{
+    let mut symmetric = HashMap::new();
+    for (origin, destination) in table.keys() {
+        let forward = table[&(origin.clone(), destination.clone())];
+        let backward = table
+            .get(&(destination.clone(), origin.clone()))
+            .copied()
+            .unwrap_or_default();
+        symmetric.insert((origin.clone(), destination.clone()), forward + backward);
+        symmetric.insert((destination.clone(), origin.clone()), forward + backward);
+    }
+    symmetric
+}
+
+pub fn get_all_nodes<W>(edges: &[Edge<W>]) -> HashSet<String> {
+    let mut nodes = HashSet::new();
+    for edge in edges {
+        nodes.insert(edge.origin.clone());
+        nodes.insert(edge.destination.clone());
+    }
+    nodes
+}
+
+pub fn score_route<W>(route: &[&String], table: &WeightTable<W>, shape: RouteShape) -> W
+where
+    W: Copy + Default + Add<Output = W>,
+{
+    let mut score = W::default();
+    for (node_a, node_b) in route.iter().tuple_windows() {
+        score = score + table[&((*node_a).clone(), (*node_b).clone())];
+    }
+    if shape == RouteShape::Cycle && route.len() > 1 {
+        let (first, last) = (route[0], route[route.len() - 1]);
+        score = score + table[&(last.clone(), first.clone())];
+    }
+    score
+}
+
+// Yields the score of every possible route through all the nodes. Cycles are
+// anchored on one node, so rotations of the same cycle are only scored once.
+pub fn route_scores<'a, W>(
+    nodes: &'a HashSet<String>,
+    table: &'a WeightTable<W>,
+    shape: RouteShape,
+) -> Box<dyn Iterator<Item = W> + 'a>
+where
+    W: Copy + Default + Add<Output = W> + 'a,
+{
+    match shape {
+        RouteShape::Path => Box::new(
+            nodes
+                .iter()
+                .permutations(nodes.len())
+                .map(move |route| score_route(&route, table, shape)),
+        ),
+        RouteShape::Cycle => {
+            let mut sorted_nodes: Vec<&String> = nodes.iter().collect();
+            sorted_nodes.sort();
+            let (anchor, rest) = match sorted_nodes.split_first() {
+                Some((anchor, rest)) => (*anchor, rest.to_vec()),
+                None => return Box::new(std::iter::empty()),
+            };
+            let rest_len = rest.len();
+            Box::new(rest.into_iter().permutations(rest_len).map(move |tail| {
+                let mut route = vec![anchor];
+                route.extend(tail);
+                score_route(&route, table, shape)
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edge(origin: &str, destination: &str, weight: i32) -> Edge<i32> {
+        Edge {
+            origin: String::from(origin),
+            destination: String::from(destination),
+            weight,
+        }
+    }
+
+    #[test]
+    fn test_construct_weight_table() {
+        let edges = vec![edge("a", "b", 1), edge("b", "a", 2)];
+        let directed = construct_weight_table(&edges, Direction::Directed);
+        assert_eq!(directed[&(String::from("a"), String::from("b"))], 1);
+        assert_eq!(directed[&(String::from("b"), String::from("a"))], 2);
+
+        let edges = vec![edge("a", "b", 1)];
+        let undirected = construct_weight_table(&edges, Direction::Undirected);
+        assert_eq!(undirected[&(String::from("a"), String::from("b"))], 1);
+        assert_eq!(undirected[&(String::from("b"), String::from("a"))], 1);
+    }
+
+    #[test]
+    fn test_symmetrize() {
+        let edges = vec![edge("a", "b", 5), edge("b", "a", -2), edge("a", "c", 3)];
+        let table = symmetrize(&construct_weight_table(&edges, Direction::Directed));
+        assert_eq!(table[&(String::from("a"), String::from("b"))], 3);
+        assert_eq!(table[&(String::from("b"), String::from("a"))], 3);
+        assert_eq!(table[&(String::from("c"), String::from("a"))], 3);
+        assert_eq!(table.len(), 4);
+    }
+
+    #[test]
+    fn test_score_route() {
+        let edges = vec![edge("a", "b", 1), edge("b", "c", 2), edge("c", "a", 4)];
+        let table = construct_weight_table(&edges, Direction::Undirected);
+        let (a, b, c) = (String::from("a"), String::from("b"), String::from("c"));
+        assert_eq!(score_route(&[&a, &b, &c], &table, RouteShape::Path), 3);
+        assert_eq!(score_route(&[&a, &b, &c], &table, RouteShape::Cycle), 7);
+        assert_eq!(score_route(&[&a], &table, RouteShape::Cycle), 0);
+    }
+
+    #[test]
+    fn test_route_scores() {
+        let edges = vec![edge("a", "b", 1), edge("b", "c", 2), edge("c", "a", 4)];
+        let table = construct_weight_table(&edges, Direction::Undirected);
+        let nodes = get_all_nodes(&edges);
+        let paths: Vec<i32> = route_scores(&nodes, &table, RouteShape::Path).collect();
+        assert_eq!(paths.len(), 6);
+        assert_eq!(paths.iter().min(), Some(&3));
+        assert_eq!(paths.iter().max(), Some(&6));
+        let cycles: Vec<i32> = route_scores(&nodes, &table, RouteShape::Cycle).collect();
+        assert_eq!(cycles, vec![7, 7]);
+        assert_eq!(
+            route_scores(&HashSet::new(), &table, RouteShape::Cycle).count(),
+            0
+        );
+    }
+}
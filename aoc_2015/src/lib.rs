@@ -0,0 +1,21 @@
+pub mod days;
+pub mod graph;
+
+use aoc_common::{Solver, Year};
+use std::path::{Path, PathBuf};
+
+pub struct Aoc2015;
+
+impl Year for Aoc2015 {
+    fn year(&self) -> u16 {
+        2015
+    }
+
+    fn crate_dir(&self) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).to_path_buf()
+    }
+
+    fn solvers(&self) -> &[&'static dyn Solver] {
+        days::SOLVERS
+    }
+}
@@ -0,0 +1,42 @@
+use aoc_2015::days::{day07, day09};
+use aoc_2015::Aoc2015;
+use aoc_common::{Budget, Year};
+
+#[test]
+fn test_wire_circuit_from_library() {
+    let wires = day07::wire_circuit(
+        "123 -> x\n456 -> y\nx AND y -> d\nx OR y -> e\nNOT x -> h",
+        &Budget::unlimited(),
+    )
+    .unwrap();
+    assert_eq!(wires.get("d"), Some(&72));
+    assert_eq!(wires.get("e"), Some(&507));
+    assert_eq!(wires.get("h"), Some(&65412));
+}
+
+#[test]
+fn test_route_solver_from_library() {
+    let input = "London to Dublin = 464\nLondon to Belfast = 518\nDublin to Belfast = 141";
+    let distances = day09::parse_list_into_distance(input);
+    let table = day09::construct_distance_table(&distances);
+    let cities = day09::get_all_locations(&distances);
+    assert_eq!(day09::calculate_shortest_distance(&cities, &table), 605);
+    assert_eq!(day09::calculate_longest_distance(&cities, &table), 982);
+}
+
+#[test]
+fn test_year_solvers() {
+    for (index, solver) in Aoc2015.solvers().iter().enumerate() {
+        assert_eq!(solver.day() as usize, index + 1);
+    }
+    assert_eq!(Aoc2015.solver(25).unwrap().parts(), 1);
+    assert!(Aoc2015.solver(0).is_none());
+    assert!(Aoc2015.solver(26).is_none());
+    assert_eq!(
+        Aoc2015
+            .solver(9)
+            .unwrap()
+            .part1("London to Dublin = 464\nLondon to Belfast = 518\nDublin to Belfast = 141"),
+        Ok(String::from("605"))
+    );
+}
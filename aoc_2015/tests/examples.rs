@@ -0,0 +1,2 @@
+// One test per `examples/dayNN/<name>.txt` and expected part, generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,105 @@
+use crate::error::AocError;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// How long a solver may keep searching. Long running solvers call `check`
+// every so often and give up with its error, clones share cancellation
+#[derive(Clone, Debug)]
+pub struct Budget {
+    limit: Option<Duration>,
+    deadline: Option<Instant>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Budget {
+    pub fn unlimited() -> Budget {
+        Budget {
+            limit: None,
+            deadline: None,
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    // counted from now
+    pub fn timeout(limit: Duration) -> Budget {
+        Budget {
+            limit: Some(limit),
+            deadline: Instant::now().checked_add(limit),
+            ..Budget::unlimited()
+        }
+    }
+
+    // counted from now, and cancelled along with `self`
+    pub fn with_timeout(&self, limit: Duration) -> Budget {
+        Budget {
+            cancelled: Arc::clone(&self.cancelled),
+            ..Budget::timeout(limit)
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn check(&self) -> Result<(), AocError> {
+        if self.is_cancelled() {
+            return Err(AocError::Timeout(String::from("cancelled")));
+        }
+        match (self.deadline, self.limit) {
+            (Some(deadline), Some(limit)) if Instant::now() >= deadline => Err(AocError::Timeout(
+                format!("gave up after {}s", limit.as_secs_f64()),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlimited() {
+        let budget = Budget::unlimited();
+        assert_eq!(budget.check(), Ok(()));
+        budget.clone().cancel();
+        assert_eq!(
+            budget.check(),
+            Err(AocError::Timeout(String::from("cancelled")))
+        );
+    }
+
+    #[test]
+    fn test_timeout() {
+        assert_eq!(Budget::timeout(Duration::from_secs(60)).check(), Ok(()));
+        assert_eq!(
+            Budget::timeout(Duration::ZERO).check(),
+            Err(AocError::Timeout(String::from("gave up after 0s")))
+        );
+        assert_eq!(
+            Budget::timeout(Duration::from_millis(1500)).limit,
+            Some(Duration::from_millis(1500))
+        );
+    }
+
+    #[test]
+    fn test_with_timeout() {
+        let parent = Budget::unlimited();
+        let child = parent.with_timeout(Duration::from_secs(60));
+        assert_eq!(child.limit, Some(Duration::from_secs(60)));
+        assert_eq!(child.check(), Ok(()));
+        parent.cancel();
+        assert!(child.is_cancelled());
+        assert_eq!(
+            child.check(),
+            Err(AocError::Timeout(String::from("cancelled")))
+        );
+        // budgets handed out afterwards start cancelled
+        assert!(parent.with_timeout(Duration::ZERO).is_cancelled());
+    }
+}
@@ -0,0 +1,176 @@
+use crate::config::Config;
+use crate::error::AocError;
+use crate::input;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const USER_AGENT: &str = concat!("aoc_common/", env!("CARGO_PKG_VERSION"));
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+// Talks to the puzzle site as the user owning `session`
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new().timeout(TIMEOUT).build(),
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Result<Client, AocError> {
+        match &config.session {
+            Some(session) => Ok(Client::new(&config.base_url, session)),
+            None => Err(AocError::Config(String::from(
+                "no session cookie, set AOC_SESSION or `session` in the config file",
+            ))),
+        }
+    }
+
+    fn request(&self, method: &str, path: &str) -> ureq::Request {
+        self.agent
+            .request(method, &format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+    }
+
+    fn read(result: Result<ureq::Response, ureq::Error>) -> Result<String, AocError> {
+        match result {
+            Ok(response) => response
+                .into_string()
+                .map_err(|why| AocError::Http(why.to_string())),
+            Err(ureq::Error::Status(status, response)) => {
+                let url = response.get_url().to_string();
+                let body = response.into_string().unwrap_or_default();
+                Err(AocError::Http(format!(
+                    "{} answered {}: {}",
+                    url,
+                    status,
+                    body.trim()
+                )))
+            }
+            Err(why) => Err(AocError::Http(why.to_string())),
+        }
+    }
+
+    pub fn download_input(&self, year: u16, day: u8) -> Result<String, AocError> {
+        let path = format!("/{}/day/{}/input", year, day);
+        Client::read(self.request("GET", &path).call())
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, AocError> {
+        Client::read(self.request("POST", path).send_form(form))
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+// Downloads the day's input into `dir` unless it is already there. An empty
+// file (as left by `new-day`) doesn't count as cached
+pub fn fetch_input(config: &Config, year: u16, dir: &Path, day: u8) -> Result<Fetched, AocError> {
+    let path = input::input_path(dir, day);
+    if fs::metadata(&path).is_ok_and(|x| x.len() > 0) {
+        return Ok(Fetched::Cached(path));
+    }
+    let text = Client::from_config(config)?.download_input(year, day)?;
+    fs::create_dir_all(dir)
+        .map_err(|why| AocError::Io(format!("couldn't create {}: {}", dir.display(), why)))?;
+    fs::write(&path, text)
+        .map_err(|why| AocError::Io(format!("couldn't write {}: {}", path.display(), why)))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock;
+    use std::env;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_common_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn config(base_url: &str) -> Config {
+        Config {
+            base_url: String::from(base_url),
+            session: Some(String::from("cafe")),
+        }
+    }
+
+    #[test]
+    fn test_fetch_input_downloads_once() {
+        let (base_url, server) = mock::serve(vec![mock::response(200, "1113222113\n")]);
+        let dir = temp_dir("fetch");
+        let path = input::input_path(&dir, 10);
+
+        assert_eq!(
+            fetch_input(&config(&base_url), 2015, &dir, 10),
+            Ok(Fetched::Downloaded(path.clone()))
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1113222113\n");
+        // the mock only answers once, a second download would fail
+        assert_eq!(
+            fetch_input(&config(&base_url), 2015, &dir, 10),
+            Ok(Fetched::Cached(path))
+        );
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2015/day/10/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=cafe\r\n"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_input_refills_empty_file() {
+        let (base_url, server) = mock::serve(vec![mock::response(200, "yzbqklnj")]);
+        let dir = temp_dir("refill");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(input::input_path(&dir, 4), "").unwrap();
+        assert!(matches!(
+            fetch_input(&config(&base_url), 2015, &dir, 4),
+            Ok(Fetched::Downloaded(_))
+        ));
+        assert_eq!(
+            fs::read_to_string(input::input_path(&dir, 4)).unwrap(),
+            "yzbqklnj"
+        );
+        server.join().unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_input_errors() {
+        let (base_url, server) = mock::serve(vec![mock::response(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        )]);
+        let dir = temp_dir("errors");
+        match fetch_input(&config(&base_url), 2015, &dir, 1) {
+            Err(AocError::Http(why)) => assert!(why.contains("400") && why.contains("log in")),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(!input::input_path(&dir, 1).exists());
+        server.join().unwrap();
+
+        let no_session = Config {
+            base_url: base_url.clone(),
+            session: None,
+        };
+        assert!(matches!(
+            fetch_input(&no_session, 2015, &dir, 1),
+            Err(AocError::Config(_))
+        ));
+    }
+}
@@ -0,0 +1,93 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// Where the site lives and who we are on it. Read from `key = value` lines in
+// the config file, each key can be overridden by an `AOC_<KEY>` variable
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Config {
+    pub base_url: String,
+    pub session: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            base_url: String::from(DEFAULT_BASE_URL),
+            session: None,
+        }
+    }
+}
+
+impl Config {
+    pub fn parse<F>(file_text: Option<&str>, var: F) -> Config
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let mut config = Config::default();
+        let lines = file_text.unwrap_or("").lines();
+        let settings = lines
+            .map(|x| x.trim())
+            .filter(|x| !x.is_empty() && !x.starts_with('#'))
+            .filter_map(|x| x.split_once('='))
+            .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()));
+        let overrides = ["base_url", "session"].iter().filter_map(|key| {
+            var(&format!("AOC_{}", key.to_uppercase())).map(|x| (key.to_string(), x))
+        });
+        for (key, value) in settings.chain(overrides) {
+            match key.as_str() {
+                "base_url" => config.base_url = value.trim_end_matches('/').to_string(),
+                "session" => config.session = Some(value),
+                _ => (),
+            }
+        }
+        config
+    }
+
+    // `AOC_CONFIG` or ~/.config/aoc/config
+    pub fn path() -> Option<PathBuf> {
+        env::var_os("AOC_CONFIG").map(PathBuf::from).or_else(|| {
+            env::var_os("HOME").map(|x| PathBuf::from(x).join(".config").join("aoc").join("config"))
+        })
+    }
+
+    pub fn load() -> Config {
+        let file_text = Config::path().and_then(|x| fs::read_to_string(x).ok());
+        Config::parse(file_text.as_deref(), |x| env::var(x).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_defaults() {
+        assert_eq!(Config::parse(None, |_| None), Config::default());
+        assert_eq!(Config::default().base_url, "https://adventofcode.com");
+    }
+
+    #[test]
+    fn test_parse_file() {
+        let text = "# advent of code\nsession = 53616c7465\nbase_url = http://127.0.0.1:8080/\nunknown = 1\n";
+        assert_eq!(
+            Config::parse(Some(text), |_| None),
+            Config {
+                base_url: String::from("http://127.0.0.1:8080"),
+                session: Some(String::from("53616c7465")),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_env_overrides_file() {
+        let config = Config::parse(Some("session = from_file"), |key| match key {
+            "AOC_SESSION" => Some(String::from("from_env")),
+            _ => None,
+        });
+        assert_eq!(config.session, Some(String::from("from_env")));
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+    }
+}
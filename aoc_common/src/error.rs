@@ -0,0 +1,89 @@
+use aoc_examples::ExampleError;
+use std::fmt;
+
+// Everything a solver or the runner can fail with, printable as is
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum AocError {
+    Io(String),
+    Parse(String),
+    NoSolution(String),
+    UnknownDay(u8),
+    NoModes(u8),
+    Usage(String),
+    // refusing to overwrite or redo something
+    Conflict(String),
+    Config(String),
+    Http(String),
+    // a solver ran out of its `Budget`
+    Timeout(String),
+    // a solver panicked, caught so the other days can carry on
+    Panicked(String),
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Io(why) => write!(f, "{}", why),
+            AocError::Parse(why) => write!(f, "invalid input: {}", why),
+            AocError::NoSolution(why) => write!(f, "{}", why),
+            AocError::UnknownDay(day) => write!(f, "day {} is not implemented", day),
+            AocError::NoModes(day) => write!(f, "day {} has no extra modes", day),
+            AocError::Usage(usage) => write!(f, "usage: {}", usage),
+            AocError::Conflict(why) => write!(f, "{}", why),
+            AocError::Config(why) => write!(f, "config: {}", why),
+            AocError::Http(why) => write!(f, "http: {}", why),
+            AocError::Timeout(why) => write!(f, "timeout: {}", why),
+            AocError::Panicked(why) => write!(f, "panicked: {}", why),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+impl From<ExampleError> for AocError {
+    fn from(error: ExampleError) -> Self {
+        match error {
+            ExampleError::Io(why) => AocError::Io(why),
+            ExampleError::Parse(why) => AocError::Parse(why),
+        }
+    }
+}
+
+// The printable answer of one part
+pub type Answer = Result<String, AocError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            AocError::Parse(String::from("line 2: bad")).to_string(),
+            "invalid input: line 2: bad"
+        );
+        assert_eq!(
+            AocError::UnknownDay(26).to_string(),
+            "day 26 is not implemented"
+        );
+        assert_eq!(AocError::NoModes(1).to_string(), "day 1 has no extra modes");
+        assert_eq!(
+            AocError::Usage(String::from("14 [seconds]")).to_string(),
+            "usage: 14 [seconds]"
+        );
+        assert_eq!(
+            AocError::Timeout(String::from("cancelled")).to_string(),
+            "timeout: cancelled"
+        );
+    }
+
+    #[test]
+    fn test_from_example_error() {
+        let error = ExampleError::Parse(String::from("example a has no expected answers"));
+        assert_eq!(error.to_string(), AocError::from(error.clone()).to_string());
+        assert_eq!(
+            AocError::from(ExampleError::Io(String::from("gone"))),
+            AocError::Io(String::from("gone"))
+        );
+    }
+}
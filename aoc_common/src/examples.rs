@@ -0,0 +1,28 @@
+// The example files themselves are handled by `aoc_examples`, this adds the
+// part that needs a year's solvers
+pub use aoc_examples::{
+    example_names, examples_dir, generate_tests, load_example, Example, ExampleError,
+};
+
+use crate::runner::{self, Year};
+
+// Runs one part of the example through the year's solver, panicking with a
+// readable message on a mismatch. Meant to be called from generated tests
+pub fn assert_example(year: &dyn Year, day: u8, name: &str, part: u8) {
+    let example = load_example(&year.crate_dir(), day, name).unwrap();
+    let expected = example
+        .expected
+        .iter()
+        .find(|(x, _)| *x == part)
+        .map(|(_, answer)| answer)
+        .unwrap_or_else(|| panic!("example {} has no answer for part {}", name, part));
+    let solver = year.solver(day).unwrap();
+    match runner::solve_part(solver, part, &example.input) {
+        Ok(answer) => assert_eq!(
+            &answer, expected,
+            "day {} example {} part {}",
+            day, name, part
+        ),
+        Err(why) => panic!("day {} example {} part {} failed: {}", day, name, part, why),
+    }
+}
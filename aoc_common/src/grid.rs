@@ -0,0 +1,124 @@
+// Fixed size 2D grid, first used by the 2015 light puzzles (day 6, day 18)
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Copy> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    // `rows` must all have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |x| x.len());
+        assert!(rows.iter().all(|x| x.len() == width), "ragged grid rows");
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> T {
+        self.cells[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: T) {
+        self.cells[y * self.width + x] = value;
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    // the up to 8 cells around (x, y). Cells outside the grid are skipped
+    pub fn neighbours(&self, x: usize, y: usize) -> Vec<T> {
+        let mut neighbours = Vec::with_capacity(8);
+        for dy in -1..=1i64 {
+            for dx in -1..=1i64 {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+                let (nx, ny) = (x as i64 + dx, y as i64 + dy);
+                if nx >= 0 && ny >= 0 && (nx as usize) < self.width && (ny as usize) < self.height {
+                    neighbours.push(self.get(nx as usize, ny as usize));
+                }
+            }
+        }
+        neighbours
+    }
+
+    // Cellular automaton generation: every cell is computed from the previous
+    // generation, so updates never see each other.
+    pub fn step<F>(&self, rule: F) -> Self
+    where
+        F: Fn(T, &[T]) -> T,
+    {
+        let mut next = self.clone();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                next.set(x, y, rule(self.get(x, y), &self.neighbours(x, y)));
+            }
+        }
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_set() {
+        let mut grid = Grid::new(3, 2, 0);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        grid.set(2, 1, 5);
+        assert_eq!(grid.get(2, 1), 5);
+        assert_eq!(grid.get(1, 1), 0);
+        assert_eq!(grid.cells().sum::<i32>(), 5);
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(0, 1), 4);
+        assert_eq!(grid.get(2, 0), 3);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        assert_eq!(grid.neighbours(1, 1), vec![1, 2, 3, 4, 6, 7, 8, 9]);
+        assert_eq!(grid.neighbours(0, 0), vec![2, 4, 5]);
+        assert_eq!(grid.neighbours(2, 2), vec![5, 6, 8]);
+    }
+
+    #[test]
+    fn test_step() {
+        let grid = Grid::from_rows(vec![vec![1, 0], vec![0, 0]]);
+        let next = grid.step(|cell, neighbours| cell + neighbours.iter().sum::<i32>());
+        assert_eq!(next, Grid::from_rows(vec![vec![1, 1], vec![1, 1]]));
+        // the source generation is left untouched
+        assert_eq!(grid.get(1, 1), 0);
+    }
+}
@@ -0,0 +1,42 @@
+use crate::error::AocError;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Inputs live next to each year crate as `input/dayNN.txt`
+pub fn input_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{:02}.txt", day))
+}
+
+pub fn load_input(dir: &Path, day: u8) -> Result<String, AocError> {
+    let path = input_path(dir, day);
+    fs::read_to_string(&path)
+        .map_err(|why| AocError::Io(format!("couldn't read {}: {}", path.display(), why)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(
+            input_path(Path::new("input"), 7),
+            PathBuf::from("input/day07.txt")
+        );
+        assert_eq!(
+            input_path(Path::new("input"), 25),
+            PathBuf::from("input/day25.txt")
+        );
+    }
+
+    #[test]
+    fn test_load_input() {
+        let dir = env::temp_dir().join(format!("aoc_common_input_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(input_path(&dir, 3), "^>v<\n>>\n").unwrap();
+        assert_eq!(load_input(&dir, 3), Ok(String::from("^>v<\n>>\n")));
+        assert!(matches!(load_input(&dir, 4), Err(AocError::Io(_))));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
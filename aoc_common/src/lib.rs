@@ -0,0 +1,20 @@
+pub mod budget;
+pub mod client;
+pub mod config;
+pub mod error;
+pub mod examples;
+pub mod grid;
+pub mod input;
+#[cfg(test)]
+mod mock;
+pub mod parse;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+pub mod summary;
+pub mod watch;
+
+pub use budget::Budget;
+pub use error::{Answer, AocError};
+pub use runner::{Solver, Year};
@@ -0,0 +1,63 @@
+use crate::error::AocError;
+use std::fmt::Display;
+use std::str::FromStr;
+
+// Parses every non blank line, reporting the first failure with its line number
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(line_number, line)| {
+            line.parse()
+                .map_err(|why| AocError::Parse(format!("line {}: {}", line_number + 1, why)))
+        })
+        .collect()
+}
+
+// Whitespace separated numbers, one per line or all on one line
+pub fn parse_numbers<T>(input: &str) -> Result<Vec<T>, AocError>
+where
+    T: FromStr,
+{
+    input
+        .split_whitespace()
+        .map(|x| {
+            x.parse()
+                .map_err(|_| AocError::Parse(format!("not a number: {}", x)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(parse_lines::<i32>("1\n-2\n\n3\n"), Ok(vec![1, -2, 3]));
+        assert_eq!(parse_lines::<i32>(""), Ok(vec![]));
+        assert_eq!(
+            parse_lines::<i32>("1\n\nx"),
+            Err(AocError::Parse(String::from(
+                "line 3: invalid digit found in string"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        assert_eq!(
+            parse_numbers::<u64>("20 15\n10\n5 5"),
+            Ok(vec![20, 15, 10, 5, 5])
+        );
+        assert_eq!(
+            parse_numbers::<u64>("1 -1"),
+            Err(AocError::Parse(String::from("not a number: -1")))
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::budget::Budget;
+use crate::error::{Answer, AocError};
+use crate::runner::{self, Solver};
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Format {
+    Text,
+    // one JSON object per line, for dashboards and scripts
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = AocError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(AocError::Usage(format!("unknown format: {}", text))),
+        }
+    }
+}
+
+// The outcome of solving one part
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub duration: Duration,
+    pub input_hash: String,
+}
+
+// Lets results be matched to the input they were computed from without
+// publishing the input itself
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|x| format!("{:02x}", x))
+        .collect()
+}
+
+pub fn json_string(text: &str) -> String {
+    let mut escaped = String::from("\"");
+    for character in text.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            x if (x as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", x as u32)),
+            x => escaped.push(x),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+impl Record {
+    // solves one part, timing only the solver itself
+    pub fn solve(
+        solver: &dyn Solver,
+        part: u8,
+        input: &str,
+        input_hash: &str,
+        budget: &Budget,
+    ) -> Record {
+        let start = Instant::now();
+        let answer = runner::solve_part_within(solver, part, input, budget);
+        Record {
+            day: solver.day(),
+            part,
+            answer,
+            duration: start.elapsed(),
+            input_hash: input_hash.to_string(),
+        }
+    }
+
+    pub fn to_text(&self) -> String {
+        match &self.answer {
+            Ok(answer) => format!("day {} part {}: {}", self.day, self.part, answer),
+            Err(why) => format!("day {} part {}: error: {}", self.day, self.part, why),
+        }
+    }
+
+    // failed parts carry a null answer and the error message
+    pub fn to_json(&self) -> String {
+        let (answer, error) = match &self.answer {
+            Ok(answer) => (json_string(answer), String::new()),
+            Err(why) => (
+                String::from("null"),
+                format!(",\"error\":{}", json_string(&why.to_string())),
+            ),
+        };
+        format!(
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"duration_ns\":{},\"input_hash\":{}{}}}",
+            self.day,
+            self.part,
+            answer,
+            self.duration.as_nanos(),
+            json_string(&self.input_hash),
+            error
+        )
+    }
+
+    pub fn format(&self, format: Format) -> String {
+        match format {
+            Format::Text => self.to_text(),
+            Format::Json => self.to_json(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(answer: Answer) -> Record {
+        Record {
+            day: 9,
+            part: 1,
+            answer,
+            duration: Duration::from_micros(1500),
+            input_hash: input_hash("abc"),
+        }
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(
+            input_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("vzbxxyzz"), "\"vzbxxyzz\"");
+        assert_eq!(json_string("a \"b\"\\\n"), "\"a \\\"b\\\"\\\\\\n\"");
+        assert_eq!(json_string("\u{1}"), "\"\\u0001\"");
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            record(Ok(String::from("117"))).to_json(),
+            "{\"day\":9,\"part\":1,\"answer\":\"117\",\"duration_ns\":1500000,\"input_hash\":\"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad\"}"
+        );
+        let failed = record(Err(AocError::NoSolution(String::from("no route")))).to_json();
+        assert!(failed.contains("\"answer\":null,"));
+        assert!(failed.ends_with(",\"error\":\"no route\"}"));
+    }
+
+    #[test]
+    fn test_to_text() {
+        assert_eq!(
+            record(Ok(String::from("117"))).format(Format::Text),
+            "day 9 part 1: 117"
+        );
+        assert_eq!(
+            record(Err(AocError::NoSolution(String::from("no route")))).to_text(),
+            "day 9 part 1: error: no route"
+        );
+    }
+}
@@ -0,0 +1,537 @@
+use crate::budget::Budget;
+use crate::client::{self, Fetched};
+use crate::config::Config;
+use crate::error::{Answer, AocError};
+use crate::input;
+use crate::report::{self, Format, Record};
+use crate::scaffold;
+use crate::submit::{self, History};
+use crate::summary;
+use crate::watch;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::process;
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// One puzzle day. `input` is always the raw puzzle input
+pub trait Solver: Sync {
+    fn day(&self) -> u8;
+
+    fn part1(&self, input: &str) -> Answer;
+
+    fn part2(&self, _input: &str) -> Answer {
+        Err(AocError::NoSolution(format!(
+            "day {} has no part 2",
+            self.day()
+        )))
+    }
+
+    // Solvers with unbounded searches override these to check `budget` as
+    // they go, and have `part1`/`part2` call them with `Budget::unlimited()`
+    fn part1_within(&self, input: &str, _budget: &Budget) -> Answer {
+        self.part1(input)
+    }
+
+    fn part2_within(&self, input: &str, _budget: &Budget) -> Answer {
+        self.part2(input)
+    }
+
+    // the last day of a year only has one puzzle
+    fn parts(&self) -> u8 {
+        2
+    }
+
+    // day specific extra modes (reports, traces...), driven by command line args
+    fn run_mode(&self, _input: &str, _args: &[String]) -> Answer {
+        Err(AocError::NoModes(self.day()))
+    }
+}
+
+// A year crate plugs into the runner by listing its solvers
+pub trait Year: Sync {
+    fn year(&self) -> u16;
+
+    // root of the year crate, holding `src/days` and `input`
+    fn crate_dir(&self) -> PathBuf;
+
+    fn input_dir(&self) -> PathBuf {
+        self.crate_dir().join("input")
+    }
+
+    fn solvers(&self) -> &[&'static dyn Solver];
+
+    fn solver(&self, day: u8) -> Option<&'static dyn Solver> {
+        self.solvers().iter().find(|x| x.day() == day).copied()
+    }
+}
+
+// How long `run` lets each part search unless told otherwise
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+pub fn solve_part(solver: &dyn Solver, part: u8, input: &str) -> Answer {
+    solve_part_within(solver, part, input, &Budget::unlimited())
+}
+
+pub fn solve_part_within(solver: &dyn Solver, part: u8, input: &str, budget: &Budget) -> Answer {
+    match part {
+        1 => solver.part1_within(input, budget),
+        2 if solver.parts() >= 2 => solver.part2_within(input, budget),
+        _ => Err(AocError::NoSolution(format!(
+            "day {} has no part {}",
+            solver.day(),
+            part
+        ))),
+    }
+}
+
+// `solve_part_within`, with a panic turned into an error. Many parsers still
+// panic on malformed input
+pub fn solve_part_caught(solver: &dyn Solver, part: u8, input: &str, budget: &Budget) -> Answer {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        solve_part_within(solver, part, input, budget)
+    }))
+    .unwrap_or_else(|payload| {
+        let why = match (
+            payload.downcast_ref::<&str>(),
+            payload.downcast_ref::<String>(),
+        ) {
+            (Some(why), _) => why.to_string(),
+            (_, Some(why)) => why.clone(),
+            _ => String::from("unknown cause"),
+        };
+        Err(AocError::Panicked(why))
+    })
+}
+
+// The budget every solve started from the command line descends from. The
+// handler goes in on first use, so commands that never solve anything keep
+// the default Ctrl-C. The first Ctrl-C cancels the running solvers, which
+// then stop at their next check; a second one exits right away
+fn interrupt() -> &'static Budget {
+    static INTERRUPT: OnceLock<Budget> = OnceLock::new();
+    INTERRUPT.get_or_init(|| {
+        let budget = Budget::unlimited();
+        let handler = budget.clone();
+        // without the handler Ctrl-C still ends the process, just abruptly
+        let _ = ctrlc::set_handler(move || {
+            if handler.is_cancelled() {
+                process::exit(130);
+            }
+            eprintln!("cancelling, press Ctrl-C again to quit");
+            handler.cancel();
+        });
+        budget
+    })
+}
+
+fn usage(year: &dyn Year) -> AocError {
+    AocError::Usage(format!(
+        "aoc_{} [run] <day> [--format text|json] [--timeout <seconds>] [mode args...] | run --all [--timeout <seconds>] | new-day <day> | fetch --day <day> | submit --day <day> --part <part> | watch --day <day> [--timeout <seconds>]",
+        year.year()
+    ))
+}
+
+// Runs the command line `args` (without the program name) against `year`,
+// returning the process exit code
+pub fn run(year: &dyn Year, args: &[String]) -> i32 {
+    let result = match args.first().map(|x| x.as_str()) {
+        Some("new-day") => new_day(year, &args[1..]),
+        Some("fetch") => fetch(year, &args[1..]),
+        Some("submit") => submit(year, &args[1..]),
+        Some("watch") => watch(year, &args[1..]),
+        Some("run") if args.iter().any(|x| x == "--all") => run_all(year, &args[1..]),
+        Some("run") => run_day(year, &args[1..]),
+        _ => run_day(year, args),
+    };
+    match result {
+        Ok(()) => 0,
+        Err(why @ AocError::Usage(_)) => {
+            eprintln!("{}", why);
+            2
+        }
+        Err(why) => {
+            eprintln!("{}", why);
+            1
+        }
+    }
+}
+
+fn parse_day(year: &dyn Year, arg: Option<&String>) -> Result<u8, AocError> {
+    arg.and_then(|x| x.parse::<u8>().ok())
+        .ok_or_else(|| usage(year))
+}
+
+// value following `--name` in `args`
+fn option<'a>(args: &'a [String], name: &str) -> Option<&'a String> {
+    args.iter()
+        .position(|x| x == name)
+        .and_then(|x| args.get(x + 1))
+}
+
+fn fetch(year: &dyn Year, args: &[String]) -> Result<(), AocError> {
+    let day = parse_day(year, option(args, "--day"))?;
+    match client::fetch_input(&Config::load(), year.year(), &year.input_dir(), day)? {
+        Fetched::Cached(path) => println!("{} is already cached", path.display()),
+        Fetched::Downloaded(path) => println!("downloaded {}", path.display()),
+    }
+    Ok(())
+}
+
+fn submit(year: &dyn Year, args: &[String]) -> Result<(), AocError> {
+    let day = parse_day(year, option(args, "--day"))?;
+    let part = match option(args, "--part").map(|x| x.as_str()) {
+        Some("1") => 1,
+        Some("2") => 2,
+        _ => return Err(usage(year)),
+    };
+    let solver = year.solver(day).ok_or(AocError::UnknownDay(day))?;
+    let input = input::load_input(&year.input_dir(), day)?;
+    let answer = solve_part_within(solver, part, &input, interrupt())?;
+    println!("day {} part {}: {}", day, part, answer);
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |x| x.as_secs());
+    let mut history = History::load(&History::path(&year.input_dir()))?;
+    let verdict = submit::submit_answer(
+        &Config::load(),
+        year.year(),
+        &mut history,
+        day,
+        part,
+        &answer,
+        now,
+    )?;
+    println!("verdict: {}", verdict);
+    Ok(())
+}
+
+fn watch(year: &dyn Year, args: &[String]) -> Result<(), AocError> {
+    let (timeout, args) = parse_timeout(args)?;
+    let day = parse_day(year, option(&args, "--day"))?;
+    year.solver(day).ok_or(AocError::UnknownDay(day))?;
+    watch::watch(year, day, timeout)
+}
+
+fn new_day(year: &dyn Year, args: &[String]) -> Result<(), AocError> {
+    let day = parse_day(year, args.first())?;
+    for path in scaffold::new_day(&year.crate_dir(), day)? {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+// Splits `--name <value>` off the rest of the args
+fn take_option(args: &[String], name: &str) -> Result<(Option<String>, Vec<String>), AocError> {
+    let position = match args.iter().position(|x| x == name) {
+        Some(position) => position,
+        None => return Ok((None, args.to_vec())),
+    };
+    let value = args
+        .get(position + 1)
+        .ok_or_else(|| AocError::Usage(format!("{} needs a value", name)))?;
+    let rest = [&args[..position], &args[position + 2..]].concat();
+    Ok((Some(value.clone()), rest))
+}
+
+fn parse_format(args: &[String]) -> Result<(Format, Vec<String>), AocError> {
+    let (format, rest) = take_option(args, "--format")?;
+    Ok((format.map_or(Ok(Format::Text), |x| x.parse())?, rest))
+}
+
+fn parse_timeout(args: &[String]) -> Result<(Duration, Vec<String>), AocError> {
+    let (timeout, rest) = take_option(args, "--timeout")?;
+    let timeout = match timeout {
+        Some(seconds) => seconds
+            .parse()
+            .ok()
+            .and_then(|x| Duration::try_from_secs_f64(x).ok())
+            .ok_or_else(|| AocError::Usage(format!("not a number of seconds: {}", seconds)))?,
+        None => DEFAULT_TIMEOUT,
+    };
+    Ok((timeout, rest))
+}
+
+fn run_all(year: &dyn Year, args: &[String]) -> Result<(), AocError> {
+    let (timeout, args) = parse_timeout(args)?;
+    if args.iter().any(|x| x != "--all") {
+        return Err(usage(year));
+    }
+    let rows = summary::solve_all(year, interrupt(), timeout);
+    println!("{}", summary::render_table(&rows));
+    match summary::failures(&rows) {
+        0 => Ok(()),
+        failed => Err(AocError::NoSolution(format!("{} parts failed", failed))),
+    }
+}
+
+fn run_day(year: &dyn Year, args: &[String]) -> Result<(), AocError> {
+    let (format, args) = parse_format(args)?;
+    let (timeout, args) = parse_timeout(&args)?;
+    let day = parse_day(year, args.first())?;
+    let solver = year.solver(day).ok_or(AocError::UnknownDay(day))?;
+    let input = input::load_input(&year.input_dir(), day)?;
+
+    // anything after the day number selects one of the day's extra modes
+    let mode_args = &args[1..];
+    if !mode_args.is_empty() {
+        if format != Format::Text {
+            return Err(AocError::Usage(String::from(
+                "day modes only produce text output",
+            )));
+        }
+        let output = solver.run_mode(&input, mode_args)?;
+        println!("{}", output.trim_end());
+        return Ok(());
+    }
+
+    let input_hash = report::input_hash(&input);
+    for part in 1..=solver.parts() {
+        println!(
+            "{}",
+            Record::solve(
+                solver,
+                part,
+                &input,
+                &input_hash,
+                &interrupt().with_timeout(timeout),
+            )
+            .format(format)
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Echo;
+
+    impl Solver for Echo {
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn part1(&self, input: &str) -> Answer {
+            Ok(input.trim().to_string())
+        }
+
+        fn part2(&self, input: &str) -> Answer {
+            Ok(input.trim().len().to_string())
+        }
+    }
+
+    struct Last;
+
+    impl Solver for Last {
+        fn day(&self) -> u8 {
+            25
+        }
+
+        fn part1(&self, _input: &str) -> Answer {
+            Ok(String::from("done"))
+        }
+
+        fn parts(&self) -> u8 {
+            1
+        }
+    }
+
+    // searches until its budget runs out
+    struct Spin;
+
+    impl Solver for Spin {
+        fn day(&self) -> u8 {
+            2
+        }
+
+        fn part1(&self, input: &str) -> Answer {
+            self.part1_within(input, &Budget::unlimited())
+        }
+
+        fn part1_within(&self, _input: &str, budget: &Budget) -> Answer {
+            loop {
+                budget.check()?;
+            }
+        }
+    }
+
+    struct Crash;
+
+    impl Solver for Crash {
+        fn day(&self) -> u8 {
+            3
+        }
+
+        fn part1(&self, input: &str) -> Answer {
+            panic!("invalid line: {}", input)
+        }
+
+        fn part2(&self, _input: &str) -> Answer {
+            panic!("no part 2")
+        }
+    }
+
+    struct Test;
+
+    impl Year for Test {
+        fn year(&self) -> u16 {
+            1999
+        }
+
+        fn crate_dir(&self) -> PathBuf {
+            PathBuf::from("does/not/exist")
+        }
+
+        fn solvers(&self) -> &[&'static dyn Solver] {
+            &[&Echo, &Last]
+        }
+    }
+
+    #[test]
+    fn test_solver_lookup() {
+        assert_eq!(Test.solver(1).map(|x| x.day()), Some(1));
+        assert_eq!(Test.solver(25).map(|x| x.day()), Some(25));
+        assert!(Test.solver(2).is_none());
+    }
+
+    #[test]
+    fn test_solve_part() {
+        assert_eq!(solve_part(&Echo, 1, "abc\n"), Ok(String::from("abc")));
+        assert_eq!(solve_part(&Echo, 2, "abc\n"), Ok(String::from("3")));
+        assert_eq!(
+            solve_part(&Last, 2, ""),
+            Err(AocError::NoSolution(String::from("day 25 has no part 2")))
+        );
+        assert_eq!(
+            Echo.run_mode("", &[String::from("trace")]),
+            Err(AocError::NoModes(1))
+        );
+    }
+
+    #[test]
+    fn test_solve_part_within() {
+        let budget = Budget::timeout(Duration::from_millis(10));
+        assert_eq!(
+            solve_part_within(&Echo, 1, "abc", &budget),
+            Ok(String::from("abc"))
+        );
+        assert!(matches!(
+            solve_part_within(&Spin, 1, "", &budget),
+            Err(AocError::Timeout(_))
+        ));
+        // what a Ctrl-C does to a part that still had plenty of time
+        let parent = Budget::unlimited();
+        let budget = parent.with_timeout(DEFAULT_TIMEOUT);
+        parent.cancel();
+        assert_eq!(
+            solve_part_within(&Spin, 1, "", &budget),
+            Err(AocError::Timeout(String::from("cancelled")))
+        );
+    }
+
+    #[test]
+    fn test_option() {
+        let args: Vec<String> = ["--day", "7", "--part"]
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        assert_eq!(option(&args, "--day"), Some(&String::from("7")));
+        assert_eq!(option(&args, "--part"), None);
+        assert_eq!(option(&args, "--year"), None);
+    }
+
+    #[test]
+    fn test_solve_part_caught() {
+        let budget = Budget::unlimited();
+        assert_eq!(
+            solve_part_caught(&Echo, 1, "abc", &budget),
+            Ok(String::from("abc"))
+        );
+        assert_eq!(
+            solve_part_caught(&Crash, 1, "x", &budget),
+            Err(AocError::Panicked(String::from("invalid line: x")))
+        );
+        assert_eq!(
+            solve_part_caught(&Crash, 2, "x", &budget),
+            Err(AocError::Panicked(String::from("no part 2")))
+        );
+    }
+
+    #[test]
+    fn test_parse_format() {
+        let args: Vec<String> = ["9", "--format", "json", "report"]
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        assert_eq!(
+            parse_format(&args),
+            Ok((
+                Format::Json,
+                vec![String::from("9"), String::from("report")]
+            ))
+        );
+        assert_eq!(
+            parse_format(&args[..1]),
+            Ok((Format::Text, args[..1].to_vec()))
+        );
+        assert!(parse_format(&args[..2]).is_err());
+        assert_eq!(
+            run(
+                &Test,
+                &[
+                    String::from("1"),
+                    String::from("--format"),
+                    String::from("xml")
+                ]
+            ),
+            2
+        );
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        let args: Vec<String> = ["4", "--timeout", "2.5"]
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        assert_eq!(
+            parse_timeout(&args),
+            Ok((Duration::from_millis(2500), vec![String::from("4")]))
+        );
+        assert_eq!(
+            parse_timeout(&args[..1]),
+            Ok((DEFAULT_TIMEOUT, args[..1].to_vec()))
+        );
+        assert!(parse_timeout(&args[..2]).is_err());
+        assert!(parse_timeout(&[String::from("--timeout"), String::from("-1")]).is_err());
+    }
+
+    #[test]
+    fn test_run_errors() {
+        assert_eq!(run(&Test, &[]), 2);
+        assert_eq!(run(&Test, &[String::from("x")]), 2);
+        assert_eq!(run(&Test, &[String::from("3")]), 1);
+        // the input file is missing
+        assert_eq!(run(&Test, &[String::from("1")]), 1);
+        assert_eq!(run(&Test, &[String::from("new-day")]), 2);
+        assert_eq!(run(&Test, &[String::from("fetch"), String::from("1")]), 2);
+        assert_eq!(run(&Test, &[String::from("watch")]), 2);
+        // every day is missing its input, the table still gets printed
+        assert_eq!(run(&Test, &[String::from("run"), String::from("--all")]), 1);
+        let all: Vec<String> = ["run", "--all", "--format", "json"]
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        assert_eq!(run(&Test, &all), 2);
+        assert_eq!(run(&Test, &[String::from("run"), String::from("1")]), 1);
+        let submit: Vec<String> = ["submit", "--day", "1", "--part", "3"]
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        assert_eq!(run(&Test, &submit), 2);
+        assert_eq!(run(&Test, &[String::from("new-day"), String::from("0")]), 2);
+    }
+}
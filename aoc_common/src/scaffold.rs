@@ -0,0 +1,209 @@
+use crate::error::AocError;
+use crate::input;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs");
+const SOLVERS_TABLE: &str = "pub const SOLVERS: &[&dyn Solver] = &[";
+
+pub fn render_day(day: u8) -> String {
+    DAY_TEMPLATE
+        .replace("{{padded}}", &format!("{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+fn day_of_line(line: &str, prefix: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix(prefix)
+        .and_then(|x| x.get(..2))
+        .and_then(|x| x.parse().ok())
+}
+
+// Index of the line the new entry goes in front of, keeping days sorted
+fn insert_position(lines: &[String], range: (usize, usize), prefix: &str, day: u8) -> usize {
+    lines[range.0..range.1]
+        .iter()
+        .position(|x| day_of_line(x, prefix).is_some_and(|other| other > day))
+        .map_or_else(
+            || {
+                lines[range.0..range.1]
+                    .iter()
+                    .rposition(|x| day_of_line(x, prefix).is_some())
+                    .map_or(range.0, |last| range.0 + last + 1)
+            },
+            |first| range.0 + first,
+        )
+}
+
+// Adds `pub mod dayNN;` and its solver to the contents of `src/days/mod.rs`
+pub fn register_day(days_mod: &str, day: u8) -> Result<String, AocError> {
+    let mut lines: Vec<String> = days_mod.lines().map(String::from).collect();
+    let table = lines
+        .iter()
+        .position(|x| x.trim() == SOLVERS_TABLE)
+        .ok_or_else(|| AocError::Parse(format!("no `{}` table found", SOLVERS_TABLE)))?;
+    if lines
+        .iter()
+        .any(|x| day_of_line(x, "pub mod day") == Some(day))
+    {
+        return Err(AocError::Conflict(format!(
+            "day {} is already registered",
+            day
+        )));
+    }
+
+    let table_end = lines[table..]
+        .iter()
+        .position(|x| x.trim() == "];")
+        .map(|x| table + x)
+        .ok_or_else(|| AocError::Parse(String::from("the solvers table is not closed")))?;
+    let entry = insert_position(&lines, (table + 1, table_end), "&day", day);
+    lines.insert(entry, format!("    &day{:02}::Day{:02},", day, day));
+
+    let modules = lines[..table]
+        .iter()
+        .position(|x| x.starts_with("pub mod day"))
+        .unwrap_or(table);
+    let module = insert_position(&lines, (modules, table), "pub mod day", day);
+    lines.insert(module, format!("pub mod day{:02};", day));
+    Ok(lines.join("\n") + "\n")
+}
+
+// Creates the day's source file and an empty input, then wires the day into
+// the year crate's dispatch table. Returns the files that were written
+pub fn new_day(crate_dir: &Path, day: u8) -> Result<Vec<PathBuf>, AocError> {
+    if !(1..=25).contains(&day) {
+        return Err(AocError::Usage(String::from("new-day <1-25>")));
+    }
+    let days_dir = crate_dir.join("src").join("days");
+    let source = days_dir.join(format!("day{:02}.rs", day));
+    if source.exists() {
+        return Err(AocError::Conflict(format!(
+            "{} already exists",
+            source.display()
+        )));
+    }
+    let mod_path = days_dir.join("mod.rs");
+    let days_mod = fs::read_to_string(&mod_path)
+        .map_err(|why| AocError::Io(format!("couldn't read {}: {}", mod_path.display(), why)))?;
+    let registered = register_day(&days_mod, day)?;
+
+    let write = |path: &Path, contents: &str| {
+        fs::write(path, contents)
+            .map_err(|why| AocError::Io(format!("couldn't write {}: {}", path.display(), why)))
+    };
+    write(&source, &render_day(day))?;
+    write(&mod_path, &registered)?;
+    let mut written = vec![source, mod_path];
+
+    // never clobber an input that was already downloaded
+    let input_dir = crate_dir.join("input");
+    let input = input::input_path(&input_dir, day);
+    if !input.exists() {
+        fs::create_dir_all(&input_dir).map_err(|why| {
+            AocError::Io(format!("couldn't create {}: {}", input_dir.display(), why))
+        })?;
+        write(&input, "")?;
+        written.push(input);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const DAYS_MOD: &str = "use aoc_common::Solver;
+
+pub mod day01;
+pub mod day03;
+
+pub const SOLVERS: &[&dyn Solver] = &[
+    &day01::Day01,
+    &day03::Day03,
+];
+";
+
+    #[test]
+    fn test_render_day() {
+        let source = render_day(7);
+        assert!(source.contains("pub struct Day07;"));
+        assert!(source.contains("impl Solver for Day07 {"));
+        assert!(source.contains("        7\n"));
+        assert!(source.contains("day 7 part 2 is not solved yet"));
+        assert!(!source.contains("{{"));
+    }
+
+    #[test]
+    fn test_register_day() {
+        assert_eq!(
+            register_day(DAYS_MOD, 2),
+            Ok(String::from(
+                "use aoc_common::Solver;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+pub const SOLVERS: &[&dyn Solver] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+];
+"
+            ))
+        );
+        let last = register_day(DAYS_MOD, 12).unwrap();
+        assert!(last.contains("pub mod day03;\npub mod day12;\n"));
+        assert!(last.contains("    &day03::Day03,\n    &day12::Day12,\n];"));
+        assert_eq!(
+            register_day(DAYS_MOD, 3),
+            Err(AocError::Conflict(String::from(
+                "day 3 is already registered"
+            )))
+        );
+        assert!(register_day("pub mod day01;\n", 2).is_err());
+    }
+
+    #[test]
+    fn test_register_first_day() {
+        let empty = "use aoc_common::Solver;\n\npub const SOLVERS: &[&dyn Solver] = &[\n];\n";
+        assert_eq!(
+            register_day(empty, 1),
+            Ok(String::from(
+                "use aoc_common::Solver;\n\npub mod day01;\npub const SOLVERS: &[&dyn Solver] = &[\n    &day01::Day01,\n];\n"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_new_day() {
+        let dir = env::temp_dir().join(format!("aoc_common_scaffold_{}", std::process::id()));
+        fs::create_dir_all(dir.join("src").join("days")).unwrap();
+        fs::write(dir.join("src").join("days").join("mod.rs"), DAYS_MOD).unwrap();
+
+        let written = new_day(&dir, 2).unwrap();
+        assert_eq!(written.len(), 3);
+        assert_eq!(
+            fs::read_to_string(dir.join("src/days/day02.rs")).unwrap(),
+            render_day(2)
+        );
+        assert_eq!(fs::read_to_string(dir.join("input/day02.txt")).unwrap(), "");
+        assert!(fs::read_to_string(dir.join("src/days/mod.rs"))
+            .unwrap()
+            .contains("pub mod day02;"));
+
+        // an existing input is kept as is
+        fs::write(dir.join("input/day04.txt"), "puzzle").unwrap();
+        assert_eq!(new_day(&dir, 4).unwrap().len(), 2);
+        assert_eq!(
+            fs::read_to_string(dir.join("input/day04.txt")).unwrap(),
+            "puzzle"
+        );
+
+        assert!(matches!(new_day(&dir, 2), Err(AocError::Conflict(_))));
+        assert!(matches!(new_day(&dir, 26), Err(AocError::Usage(_))));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,392 @@
+use crate::client::Client;
+use crate::config::Config;
+use crate::error::AocError;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+// How the site judged an answer
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    // answered too recently, nothing was judged
+    Wait,
+    // the part is already solved on the site
+    AlreadySolved,
+}
+
+impl Verdict {
+    fn is_rejection(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait => "wait",
+            Verdict::AlreadySolved => "already_solved",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = AocError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "correct" => Ok(Verdict::Correct),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "wait" => Ok(Verdict::Wait),
+            "already_solved" => Ok(Verdict::AlreadySolved),
+            _ => Err(AocError::Parse(format!("unknown verdict: {}", text))),
+        }
+    }
+}
+
+// The verdict and how many seconds to wait before the next answer
+pub fn parse_response(text: &str) -> Result<(Verdict, u64), AocError> {
+    lazy_static! {
+        static ref LEFT: Regex = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+        static ref WAIT: Regex = Regex::new(r"(?i)wait (one|\d+) minutes?").unwrap();
+    }
+    if let Some(capture) = LEFT.captures(text) {
+        let minutes: u64 = capture.get(1).map_or(0, |x| x.as_str().parse().unwrap());
+        let seconds: u64 = capture[2].parse().unwrap();
+        return Ok((Verdict::Wait, minutes * 60 + seconds));
+    }
+    let cooldown = WAIT.captures(text).map_or(0, |capture| match &capture[1] {
+        "one" => 60,
+        minutes => minutes.parse::<u64>().unwrap() * 60,
+    });
+    let verdict = if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("answer is too high") {
+        Verdict::TooHigh
+    } else if text.contains("answer is too low") {
+        Verdict::TooLow
+    } else if text.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        return Err(AocError::Parse(String::from(
+            "couldn't find a verdict in the response",
+        )));
+    };
+    Ok((verdict, cooldown))
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    // unix time before which nothing should be submitted
+    pub cooldown_until: u64,
+}
+
+impl fmt::Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}",
+            self.day, self.part, self.verdict, self.cooldown_until, self.answer
+        )
+    }
+}
+
+impl FromStr for Submission {
+    type Err = AocError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = line.splitn(5, '\t').collect();
+        let invalid = || AocError::Parse(format!("invalid submission: {}", line));
+        if fields.len() != 5 {
+            return Err(invalid());
+        }
+        Ok(Submission {
+            day: fields[0].parse().map_err(|_| invalid())?,
+            part: fields[1].parse().map_err(|_| invalid())?,
+            verdict: fields[2].parse()?,
+            cooldown_until: fields[3].parse().map_err(|_| invalid())?,
+            answer: fields[4].to_string(),
+        })
+    }
+}
+
+// Every answer sent so far, one tab separated line each
+pub struct History {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    pub fn path(input_dir: &Path) -> PathBuf {
+        input_dir.join("submissions.tsv")
+    }
+
+    pub fn load(path: &Path) -> Result<History, AocError> {
+        let submissions = match fs::read_to_string(path) {
+            Ok(text) => crate::parse::parse_lines(&text)?,
+            // nothing submitted yet
+            Err(why) if why.kind() == ErrorKind::NotFound => Vec::new(),
+            Err(why) => {
+                return Err(AocError::Io(format!(
+                    "couldn't read {}: {}",
+                    path.display(),
+                    why
+                )))
+            }
+        };
+        Ok(History {
+            path: path.to_path_buf(),
+            submissions,
+        })
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    // Refuses answers that can't be right or can't be sent yet
+    pub fn check(&self, day: u8, part: u8, answer: &str, now: u64) -> Result<(), AocError> {
+        if let Some(cooldown_until) = self.submissions.iter().map(|x| x.cooldown_until).max() {
+            if cooldown_until > now {
+                return Err(AocError::Conflict(format!(
+                    "cooling down, wait {}s before submitting",
+                    cooldown_until - now
+                )));
+            }
+        }
+        let number = answer.parse::<i64>().ok();
+        for submission in self
+            .submissions
+            .iter()
+            .filter(|x| x.day == day && x.part == part)
+        {
+            let known = submission.answer.parse::<i64>().ok();
+            let refused = match submission.verdict {
+                Verdict::Correct | Verdict::AlreadySolved => {
+                    return Err(AocError::Conflict(format!(
+                        "day {} part {} is already solved",
+                        day, part
+                    )))
+                }
+                verdict if submission.answer == answer && verdict.is_rejection() => true,
+                Verdict::TooHigh => matches!((number, known), (Some(x), Some(high)) if x >= high),
+                Verdict::TooLow => matches!((number, known), (Some(x), Some(low)) if x <= low),
+                _ => false,
+            };
+            if refused {
+                return Err(AocError::Conflict(format!(
+                    "{} can't be right, {} was {}",
+                    answer, submission.answer, submission.verdict
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, submission: Submission) -> Result<(), AocError> {
+        let write_error = |why: std::io::Error| {
+            AocError::Io(format!("couldn't write {}: {}", self.path.display(), why))
+        };
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(write_error)?;
+        writeln!(file, "{}", submission).map_err(write_error)?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+}
+
+// Sends `answer` unless the history already rules it out, recording the outcome
+pub fn submit_answer(
+    config: &Config,
+    year: u16,
+    history: &mut History,
+    day: u8,
+    part: u8,
+    answer: &str,
+    now: u64,
+) -> Result<Verdict, AocError> {
+    history.check(day, part, answer, now)?;
+    let client = Client::from_config(config)?;
+    let path = format!("/{}/day/{}/answer", year, day);
+    let level = part.to_string();
+    let text = client.post_form(&path, &[("level", &level), ("answer", answer)])?;
+    let (verdict, cooldown) = parse_response(&text)?;
+    history.record(Submission {
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+        cooldown_until: now + cooldown,
+    })?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock;
+    use std::env;
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2015/about\">about page</a>.  Please wait one minute before trying again. <a href=\"/2015/day/1\">[Return to Day 1]</a></p></article>";
+    const TOO_LOW: &str = "<p>That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.</p>";
+    const WRONG: &str =
+        "<p>That's not the right answer.  Please wait one minute before trying again.</p>";
+    const CORRECT: &str = "<p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to powering the weather machine.</p>";
+    const TOO_RECENT: &str = "<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.</p>";
+    const SOLVED: &str =
+        "<p>You don't seem to be solving the right level.  Did you already complete it?</p>";
+
+    fn history(name: &str) -> History {
+        let path = env::temp_dir().join(format!("aoc_common_{}_{}.tsv", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        History::load(&path).unwrap()
+    }
+
+    fn submission(answer: &str, verdict: Verdict, cooldown_until: u64) -> Submission {
+        Submission {
+            day: 1,
+            part: 1,
+            answer: String::from(answer),
+            verdict,
+            cooldown_until,
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(parse_response(TOO_HIGH), Ok((Verdict::TooHigh, 60)));
+        assert_eq!(parse_response(TOO_LOW), Ok((Verdict::TooLow, 300)));
+        assert_eq!(parse_response(WRONG), Ok((Verdict::Wrong, 60)));
+        assert_eq!(parse_response(CORRECT), Ok((Verdict::Correct, 0)));
+        assert_eq!(parse_response(TOO_RECENT), Ok((Verdict::Wait, 65)));
+        assert_eq!(
+            parse_response("<p>You have 12s left to wait.</p>"),
+            Ok((Verdict::Wait, 12))
+        );
+        assert_eq!(parse_response(SOLVED), Ok((Verdict::AlreadySolved, 0)));
+        assert!(parse_response("<html>maintenance</html>").is_err());
+    }
+
+    #[test]
+    fn test_submission_round_trip() {
+        let line = "12\t2\ttoo_low\t1700000060\t-3 x";
+        let parsed: Submission = line.parse().unwrap();
+        assert_eq!(parsed.day, 12);
+        assert_eq!(parsed.verdict, Verdict::TooLow);
+        assert_eq!(parsed.answer, "-3 x");
+        assert_eq!(parsed.to_string(), line);
+        assert!("12\t2\tmaybe\t0\t1".parse::<Submission>().is_err());
+        assert!("12\t2".parse::<Submission>().is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let mut history = history("check");
+        history
+            .record(submission("500", Verdict::TooHigh, 100))
+            .unwrap();
+        history
+            .record(submission("100", Verdict::TooLow, 200))
+            .unwrap();
+        history
+            .record(submission("abc", Verdict::Wrong, 300))
+            .unwrap();
+
+        assert!(matches!(
+            history.check(1, 1, "200", 299),
+            Err(AocError::Conflict(_))
+        ));
+        assert_eq!(history.check(1, 1, "200", 300), Ok(()));
+        assert!(history.check(1, 1, "500", 300).is_err());
+        assert!(history.check(1, 1, "600", 300).is_err());
+        assert!(history.check(1, 1, "100", 300).is_err());
+        assert!(history.check(1, 1, "-5", 300).is_err());
+        assert!(history.check(1, 1, "abc", 300).is_err());
+        assert_eq!(history.check(1, 1, "abd", 300), Ok(()));
+        // other parts aren't affected
+        assert_eq!(history.check(1, 2, "500", 300), Ok(()));
+
+        history
+            .record(submission("250", Verdict::Correct, 300))
+            .unwrap();
+        assert!(history.check(1, 1, "250", 300).is_err());
+
+        // the record survives a reload
+        let reloaded = History::load(&history.path).unwrap();
+        assert_eq!(reloaded.submissions(), history.submissions());
+        fs::remove_file(&history.path).unwrap();
+    }
+
+    #[test]
+    fn test_load_unreadable_history() {
+        // a directory can't be read as a file, that must not pass for empty
+        assert!(matches!(
+            History::load(&env::temp_dir()),
+            Err(AocError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, TOO_HIGH),
+            mock::response(200, TOO_RECENT),
+            mock::response(200, CORRECT),
+        ]);
+        let config = Config {
+            base_url,
+            session: Some(String::from("cafe")),
+        };
+        let mut history = history("submit");
+
+        assert_eq!(
+            submit_answer(&config, 2015, &mut history, 1, 2, "1800", 1000),
+            Ok(Verdict::TooHigh)
+        );
+        // refused locally, the server never sees these
+        assert!(submit_answer(&config, 2015, &mut history, 1, 2, "1700", 1030).is_err());
+        assert!(submit_answer(&config, 2015, &mut history, 1, 2, "1900", 1060).is_err());
+
+        assert_eq!(
+            submit_answer(&config, 2015, &mut history, 1, 2, "1783", 1060),
+            Ok(Verdict::Wait)
+        );
+        // a wait verdict doesn't count against the answer
+        assert_eq!(history.check(1, 2, "1783", 1125), Ok(()));
+        assert_eq!(
+            submit_answer(&config, 2015, &mut history, 1, 2, "1783", 1125),
+            Ok(Verdict::Correct)
+        );
+        assert!(history.check(1, 2, "1783", 2000).is_err());
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[0].starts_with("POST /2015/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("level=2&answer=1800"));
+        assert!(requests[2].ends_with("level=2&answer=1783"));
+        fs::remove_file(&history.path).unwrap();
+    }
+}
@@ -0,0 +1,150 @@
+// `run --all`: every day of a year solved concurrently, then shown as one
+// table. A failing day or part only fills its own cells
+use crate::budget::Budget;
+use crate::error::Answer;
+use crate::input;
+use crate::runner::{self, Solver, Year};
+use rayon::prelude::*;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Row {
+    pub day: u8,
+    // one per part the day has
+    pub answers: Vec<Answer>,
+    pub time: Duration,
+}
+
+// Solves every part of one day, each part gets its own `timeout` on top of
+// `budget`. A panicking part only fails its own cell
+pub fn solve_day(year: &dyn Year, solver: &dyn Solver, budget: &Budget, timeout: Duration) -> Row {
+    let start = Instant::now();
+    let answers = match input::load_input(&year.input_dir(), solver.day()) {
+        Ok(input) => (1..=solver.parts())
+            .map(|part| {
+                runner::solve_part_caught(solver, part, &input, &budget.with_timeout(timeout))
+            })
+            .collect(),
+        Err(why) => vec![Err(why)],
+    };
+    Row {
+        day: solver.day(),
+        answers,
+        time: start.elapsed(),
+    }
+}
+
+// sorted by day, whatever order the pool finished them in
+pub fn solve_all(year: &dyn Year, budget: &Budget, timeout: Duration) -> Vec<Row> {
+    let mut rows: Vec<Row> = year
+        .solvers()
+        .par_iter()
+        .map(|solver| solve_day(year, *solver, budget, timeout))
+        .collect();
+    rows.sort_by_key(|x| x.day);
+    rows
+}
+
+pub fn format_time(time: Duration) -> String {
+    match time.as_micros() {
+        x if x < 1000 => format!("{}µs", x),
+        x if x < 1_000_000 => format!("{:.1}ms", x as f64 / 1e3),
+        x => format!("{:.2}s", x as f64 / 1e6),
+    }
+}
+
+fn cell(answer: Option<&Answer>) -> String {
+    match answer {
+        Some(Ok(answer)) => answer.clone(),
+        Some(Err(why)) => format!("error: {}", why),
+        None => String::from("-"),
+    }
+}
+
+pub fn render_table(rows: &[Row]) -> String {
+    let header = ["day", "part 1", "part 2", "time"].map(String::from);
+    let lines: Vec<[String; 4]> = rows
+        .iter()
+        .map(|row| {
+            [
+                row.day.to_string(),
+                cell(row.answers.first()),
+                cell(row.answers.get(1)),
+                format_time(row.time),
+            ]
+        })
+        .collect();
+    let mut widths = header.clone().map(|x| x.chars().count());
+    for line in &lines {
+        for (width, cell) in widths.iter_mut().zip(line) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let render = |cells: &[String; 4]| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        padded.join(" | ").trim_end().to_string()
+    };
+    let rule: Vec<String> = widths.iter().map(|x| "-".repeat(*x)).collect();
+    let mut table = vec![render(&header), rule.join("-+-")];
+    table.extend(lines.iter().map(render));
+    table.join("\n")
+}
+
+// how many parts of the table failed
+pub fn failures(rows: &[Row]) -> usize {
+    rows.iter()
+        .flat_map(|x| &x.answers)
+        .filter(|x| x.is_err())
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::AocError;
+
+    fn row(day: u8, answers: Vec<Answer>, millis: u64) -> Row {
+        Row {
+            day,
+            answers,
+            time: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_time(Duration::from_micros(250)), "250µs");
+        assert_eq!(format_time(Duration::from_micros(12_340)), "12.3ms");
+        assert_eq!(format_time(Duration::from_millis(2_500)), "2.50s");
+    }
+
+    #[test]
+    fn test_render_table() {
+        let rows = vec![
+            row(
+                1,
+                vec![Ok(String::from("232")), Ok(String::from("1783"))],
+                2,
+            ),
+            row(13, vec![Err(AocError::Io(String::from("no input")))], 0),
+            row(25, vec![Ok(String::from("19980801"))], 120),
+        ];
+        assert_eq!(
+            render_table(&rows),
+            [
+                "day | part 1          | part 2 | time",
+                "----+-----------------+--------+--------",
+                "1   | 232             | 1783   | 2.0ms",
+                "13  | error: no input | -      | 0µs",
+                "25  | 19980801        | -      | 120.0ms",
+            ]
+            .join("\n")
+        );
+        assert_eq!(failures(&rows), 1);
+    }
+}
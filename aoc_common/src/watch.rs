@@ -0,0 +1,344 @@
+// `watch --day N`: polls a day's source and input, and on every change
+// rebuilds the year crate, runs the day's tests and solver through cargo and
+// shows how the answers moved since the previous run. A step that runs past
+// the timeout, or is overtaken by another change, is killed
+use crate::error::AocError;
+use crate::input;
+use crate::runner::Year;
+use std::env;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Output, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime};
+
+const POLL: Duration = Duration::from_millis(500);
+// how often a running cargo step looks at the clock and the watched files
+const STEP_POLL: Duration = Duration::from_millis(100);
+
+pub fn watched_paths(year: &dyn Year, day: u8) -> Vec<PathBuf> {
+    vec![
+        year.crate_dir()
+            .join("src")
+            .join("days")
+            .join(format!("day{:02}.rs", day)),
+        input::input_path(&year.input_dir(), day),
+    ]
+}
+
+// Modification times of the watched paths, a missing file counts as `None`
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Snapshot(Vec<Option<SystemTime>>);
+
+impl Snapshot {
+    pub fn take(paths: &[PathBuf]) -> Snapshot {
+        Snapshot(
+            paths
+                .iter()
+                .map(|x| fs::metadata(x).and_then(|x| x.modified()).ok())
+                .collect(),
+        )
+    }
+}
+
+// (part, answer) pairs out of the runner's text output. Failed parts keep
+// their `error: ...` text as the answer
+pub fn parse_answers(output: &str) -> Vec<(u8, String)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (label, answer) = line.split_once(": ")?;
+            let part = label.split(" part ").nth(1)?.parse().ok()?;
+            Some((part, answer.to_string()))
+        })
+        .collect()
+}
+
+// One line per part, comparing against the previous run
+pub fn diff_answers(previous: &[(u8, String)], current: &[(u8, String)]) -> Vec<String> {
+    current
+        .iter()
+        .map(|(part, answer)| {
+            let before = previous.iter().find(|(x, _)| x == part).map(|(_, x)| x);
+            match before {
+                Some(before) if before == answer => {
+                    format!("part {}: {} (unchanged)", part, answer)
+                }
+                Some(before) => format!("part {}: {} -> {}", part, before, answer),
+                None => format!("part {}: {} (new)", part, answer),
+            }
+        })
+        .collect()
+}
+
+// How a cargo step ended
+enum Step {
+    Finished(Output),
+    TimedOut,
+    // a watched file changed while it ran
+    Changed,
+}
+
+// Reads a child's pipe to the end on its own thread, so a chatty child never
+// blocks on a full pipe
+fn drain<R: Read + Send + 'static>(pipe: Option<R>) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut output = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut output);
+        }
+        output
+    })
+}
+
+// The child leads its own process group, so this also stops the test
+// binaries and solver that cargo started
+#[cfg(unix)]
+fn kill(child: &mut Child) {
+    use nix::sys::signal::{killpg, Signal};
+    use nix::unistd::Pid;
+    let _ = killpg(Pid::from_raw(child.id() as i32), Signal::SIGKILL);
+    let _ = child.wait();
+}
+
+#[cfg(not(unix))]
+fn kill(child: &mut Child) {
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+// Runs `command` to the end, unless it outlives `timeout` or `changed` turns
+// true first, in which case it is killed
+fn run_step(
+    mut command: Command,
+    timeout: Option<Duration>,
+    changed: &dyn Fn() -> bool,
+) -> Result<Step, AocError> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let io_error = |why| AocError::Io(format!("couldn't run {}: {}", program, why));
+    command.stdout(Stdio::piped()).stderr(Stdio::piped());
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut command, 0);
+    let mut child = command.spawn().map_err(io_error)?;
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait().map_err(io_error)? {
+            return Ok(Step::Finished(Output {
+                status,
+                stdout: stdout.join().unwrap_or_default(),
+                stderr: stderr.join().unwrap_or_default(),
+            }));
+        }
+        let step = if timeout.is_some_and(|x| start.elapsed() >= x) {
+            Step::TimedOut
+        } else if changed() {
+            Step::Changed
+        } else {
+            thread::sleep(STEP_POLL);
+            continue;
+        };
+        kill(&mut child);
+        return Ok(step);
+    }
+}
+
+fn cargo(
+    crate_dir: &Path,
+    args: &[&str],
+    timeout: Option<Duration>,
+    changed: &dyn Fn() -> bool,
+) -> Result<Step, AocError> {
+    let program = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let mut command = Command::new(program);
+    command.args(args).current_dir(crate_dir);
+    run_step(command, timeout, changed)
+}
+
+// The output of a step that ran to the end. Otherwise says why it stopped
+// and gives `None`, the rest of the rerun is skipped
+fn finished(step: Step, name: &str, timeout: Duration) -> Option<Output> {
+    match step {
+        Step::Finished(output) => Some(output),
+        Step::TimedOut => {
+            println!("{} timed out after {}s", name, timeout.as_secs_f64());
+            None
+        }
+        Step::Changed => {
+            println!("{} interrupted by a change", name);
+            None
+        }
+    }
+}
+
+// Rebuilds and reruns the day. Build and test failures are shown rather than
+// returned, the watch carries on until the next change. Tests and the solver
+// get `timeout` each, the build only stops for a change
+fn rerun(
+    year: &dyn Year,
+    day: u8,
+    timeout: Duration,
+    changed: &dyn Fn() -> bool,
+    previous: &mut Vec<(u8, String)>,
+) -> Result<(), AocError> {
+    let crate_dir = year.crate_dir();
+    let package = format!("aoc_{}", year.year());
+    let day_arg = day.to_string();
+    // matches both the module tests and the generated example tests
+    let filter = format!("day{:02}", day);
+
+    // the test binaries too, so the timeout only counts running them
+    let builds: [&[&str]; 2] = [&["build"], &["test", "--no-run"]];
+    for command in builds {
+        let args = [command, &["--quiet", "-p", &package]].concat();
+        let build = cargo(&crate_dir, &args, None, changed)?;
+        let Some(build) = finished(build, "build", timeout) else {
+            return Ok(());
+        };
+        if !build.status.success() {
+            eprint!("{}", String::from_utf8_lossy(&build.stderr));
+            println!("build failed");
+            return Ok(());
+        }
+    }
+    let tests = cargo(
+        &crate_dir,
+        &["test", "--quiet", "-p", &package, &filter],
+        Some(timeout),
+        changed,
+    )?;
+    let Some(tests) = finished(tests, "tests", timeout) else {
+        return Ok(());
+    };
+    if tests.status.success() {
+        println!("tests passed");
+    } else {
+        print!("{}", String::from_utf8_lossy(&tests.stdout));
+        println!("tests failed");
+    }
+    let solved = cargo(
+        &crate_dir,
+        &["run", "--quiet", "-p", &package, "--", &day_arg],
+        Some(timeout),
+        changed,
+    )?;
+    let Some(solved) = finished(solved, "solver", timeout) else {
+        return Ok(());
+    };
+    if !solved.status.success() {
+        eprint!("{}", String::from_utf8_lossy(&solved.stderr));
+        println!("solver failed");
+        return Ok(());
+    }
+    let current = parse_answers(&String::from_utf8_lossy(&solved.stdout));
+    for line in diff_answers(previous, &current) {
+        println!("{}", line);
+    }
+    *previous = current;
+    Ok(())
+}
+
+pub fn watch(year: &dyn Year, day: u8, timeout: Duration) -> Result<(), AocError> {
+    let paths = watched_paths(year, day);
+    for path in &paths {
+        println!("watching {}", path.display());
+    }
+    let mut seen = None;
+    let mut previous = Vec::new();
+    loop {
+        let snapshot = Snapshot::take(&paths);
+        if seen.as_ref() != Some(&snapshot) {
+            println!("== day {} ==", day);
+            let changed = || Snapshot::take(&paths) != snapshot;
+            rerun(year, day, timeout, &changed, &mut previous)?;
+            seen = Some(snapshot);
+        }
+        thread::sleep(POLL);
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(pairs: &[(u8, &str)]) -> Vec<(u8, String)> {
+        pairs.iter().map(|(x, y)| (*x, y.to_string())).collect()
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let output = "day 9 part 1: 117\nday 9 part 2: error: no route: a b\nwarning\n";
+        assert_eq!(
+            parse_answers(output),
+            answers(&[(1, "117"), (2, "error: no route: a b")])
+        );
+    }
+
+    #[test]
+    fn test_diff_answers() {
+        let previous = answers(&[(1, "117"), (2, "900")]);
+        assert_eq!(
+            diff_answers(&previous, &answers(&[(1, "117"), (2, "909")])),
+            vec!["part 1: 117 (unchanged)", "part 2: 900 -> 909"]
+        );
+        assert_eq!(
+            diff_answers(&[], &answers(&[(1, "117")])),
+            vec!["part 1: 117 (new)"]
+        );
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let dir = env::temp_dir().join(format!("aoc_common_watch_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let paths = vec![dir.join("day01.rs"), dir.join("missing.txt")];
+        fs::write(&paths[0], "a").unwrap();
+        let before = Snapshot::take(&paths);
+        assert_eq!(before.0[1], None);
+        assert_eq!(Snapshot::take(&paths), before);
+        fs::write(&paths[1], "b").unwrap();
+        assert_ne!(Snapshot::take(&paths), before);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    fn shell(script: &str) -> Command {
+        let mut command = Command::new("sh");
+        command.args(["-c", script]);
+        command
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_step() {
+        let never = || false;
+        match run_step(shell("echo out; echo err >&2"), None, &never) {
+            Ok(Step::Finished(output)) => {
+                assert!(output.status.success());
+                assert_eq!(output.stdout, b"out\n");
+                assert_eq!(output.stderr, b"err\n");
+            }
+            _ => panic!("the step should have finished"),
+        }
+
+        // both stop long before the sleep would end, background jobs included
+        let start = Instant::now();
+        let step = run_step(
+            shell("sleep 30 & sleep 30"),
+            Some(Duration::from_millis(200)),
+            &never,
+        );
+        assert!(matches!(step, Ok(Step::TimedOut)));
+        assert!(matches!(
+            run_step(shell("sleep 30"), None, &|| true),
+            Ok(Step::Changed)
+        ));
+        assert!(start.elapsed() < Duration::from_secs(10));
+
+        assert!(matches!(
+            run_step(Command::new("does/not/exist"), None, &never),
+            Err(AocError::Io(_))
+        ));
+    }
+}
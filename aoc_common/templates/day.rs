@@ -0,0 +1,42 @@
+use aoc_common::{Answer, AocError, Solver};
+
+pub struct Day{{padded}};
+
+impl Solver for Day{{padded}} {
+    fn day(&self) -> u8 {
+        {{day}}
+    }
+
+    fn part1(&self, _input: &str) -> Answer {
+        Err(AocError::NoSolution(String::from(
+            "day {{day}} part 1 is not solved yet",
+        )))
+    }
+
+    fn part2(&self, _input: &str) -> Answer {
+        Err(AocError::NoSolution(String::from(
+            "day {{day}} part 2 is not solved yet",
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // paste the example from the puzzle text and its answers, then drop the ignores.
+    // Examples can also live in examples/day{{padded}}/<name>.txt, see aoc_common::examples
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore]
+    fn test_part1() {
+        assert_eq!(Day{{padded}}.part1(EXAMPLE), Ok(String::from("")));
+    }
+
+    #[test]
+    #[ignore]
+    fn test_part2() {
+        assert_eq!(Day{{padded}}.part2(EXAMPLE), Ok(String::from("")));
+    }
+}
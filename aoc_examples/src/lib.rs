@@ -0,0 +1,196 @@
+// Puzzle examples stored as data, `examples/dayNN/<name>.txt`: the example
+// input followed by one `==> partN: <answer>` line per known answer. Kept
+// free of dependencies so build scripts can generate tests from it cheaply
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const EXPECTED: &str = "==> part";
+
+// Printed the same way as the matching `ExampleError` variants
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ExampleError {
+    Io(String),
+    Parse(String),
+}
+
+impl fmt::Display for ExampleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExampleError::Io(why) => write!(f, "{}", why),
+            ExampleError::Parse(why) => write!(f, "invalid input: {}", why),
+        }
+    }
+}
+
+impl std::error::Error for ExampleError {}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: Vec<(u8, String)>,
+}
+
+impl Example {
+    pub fn parse(name: &str, text: &str) -> Result<Example, ExampleError> {
+        let start = text
+            .lines()
+            .position(|x| x.starts_with(EXPECTED))
+            .ok_or_else(|| {
+                ExampleError::Parse(format!("example {} has no expected answers", name))
+            })?;
+        let input: String = text.split_inclusive('\n').take(start).collect();
+        let expected = text
+            .lines()
+            .skip(start)
+            .filter(|x| !x.trim().is_empty())
+            .map(|line| {
+                line.strip_prefix(EXPECTED)
+                    .and_then(|x| x.split_once(':'))
+                    .and_then(|(part, answer)| {
+                        Some((part.parse().ok()?, answer.trim().to_string()))
+                    })
+                    .ok_or_else(|| {
+                        ExampleError::Parse(format!("example {}: bad answer line {}", name, line))
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Example {
+            name: name.to_string(),
+            input,
+            expected,
+        })
+    }
+}
+
+pub fn examples_dir(crate_dir: &Path, day: u8) -> PathBuf {
+    crate_dir.join("examples").join(format!("day{:02}", day))
+}
+
+// Example names of a day, sorted. A day without examples has none
+pub fn example_names(crate_dir: &Path, day: u8) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(examples_dir(crate_dir, day))
+        .into_iter()
+        .flatten()
+        .filter_map(|x| x.ok())
+        .filter_map(|x| {
+            let path = x.path();
+            match path.extension() {
+                Some(extension) if extension == "txt" => {
+                    Some(path.file_stem()?.to_string_lossy().into_owned())
+                }
+                _ => None,
+            }
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+pub fn load_example(crate_dir: &Path, day: u8, name: &str) -> Result<Example, ExampleError> {
+    let path = examples_dir(crate_dir, day).join(format!("{}.txt", name));
+    let text = fs::read_to_string(&path)
+        .map_err(|why| ExampleError::Io(format!("couldn't read {}: {}", path.display(), why)))?;
+    Example::parse(name, &text)
+}
+
+fn test_name(day: u8, name: &str, part: u8) -> String {
+    let name: String = name
+        .chars()
+        .map(|x| {
+            if x.is_ascii_alphanumeric() {
+                x.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("day{:02}_{}_part{}", day, name, part)
+}
+
+// Source for one `#[test]` per example and part, for a build script to write
+// into OUT_DIR. `year` is the expression naming the crate's `Year`
+pub fn generate_tests(crate_dir: &Path, year: &str) -> Result<String, ExampleError> {
+    let mut source = String::new();
+    for day in 1..=25 {
+        for name in example_names(crate_dir, day) {
+            let example = load_example(crate_dir, day, &name)?;
+            for (part, _) in example.expected {
+                source.push_str(&format!(
+                    "#[test]\nfn {}() {{\n    aoc_common::examples::assert_example(&{}, {}, {:?}, {});\n}}\n\n",
+                    test_name(day, &name, part),
+                    year,
+                    day,
+                    name,
+                    part
+                ));
+            }
+        }
+    }
+    Ok(source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_parse() {
+        let text =
+            "London to Dublin = 464\nDublin to Belfast = 141\n==> part1: 605\n==> part2: 982\n";
+        assert_eq!(
+            Example::parse("cities", text),
+            Ok(Example {
+                name: String::from("cities"),
+                input: String::from("London to Dublin = 464\nDublin to Belfast = 141\n"),
+                expected: vec![(1, String::from("605")), (2, String::from("982"))],
+            })
+        );
+        // the input is kept verbatim, blank lines included
+        let example = Example::parse("blank", "a\n\n  b \n==> part2: x y\n").unwrap();
+        assert_eq!(example.input, "a\n\n  b \n");
+        assert_eq!(example.expected, vec![(2, String::from("x y"))]);
+
+        assert!(Example::parse("none", "abc\n").is_err());
+        assert!(Example::parse("bad", "abc\n==> part: 1\n").is_err());
+        assert!(Example::parse("bad", "abc\n==> part1: 1\nxyz\n").is_err());
+    }
+
+    #[test]
+    fn test_test_name() {
+        assert_eq!(
+            test_name(9, "London-Dublin", 2),
+            "day09_london_dublin_part2"
+        );
+    }
+
+    #[test]
+    fn test_generate_tests() {
+        let dir = env::temp_dir().join(format!("aoc_examples_{}", std::process::id()));
+        fs::create_dir_all(examples_dir(&dir, 3)).unwrap();
+        fs::write(
+            examples_dir(&dir, 3).join("square.txt"),
+            "^>v<\n==> part1: 4\n==> part2: 3\n",
+        )
+        .unwrap();
+        fs::write(examples_dir(&dir, 3).join("notes.md"), "ignored").unwrap();
+        fs::create_dir_all(examples_dir(&dir, 1)).unwrap();
+        fs::write(
+            examples_dir(&dir, 1).join("floor.txt"),
+            "(()\n==> part1: 1\n",
+        )
+        .unwrap();
+
+        assert_eq!(example_names(&dir, 3), vec![String::from("square")]);
+        assert!(example_names(&dir, 4).is_empty());
+        let source = generate_tests(&dir, "Year2015").unwrap();
+        assert_eq!(source.matches("#[test]").count(), 3);
+        assert!(source.starts_with(
+            "#[test]\nfn day01_floor_part1() {\n    aoc_common::examples::assert_example(&Year2015, 1, \"floor\", 1);\n}\n"
+        ));
+        assert!(source.contains("fn day03_square_part2()"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}